@@ -0,0 +1,43 @@
+//! Compares bulk against per-element reads and writes.
+
+use criterion::{Criterion, black_box, criterion_main};
+use in_situ::{Dyn, InSitu, InSituMut, Order, U32};
+
+const LEN: usize = 10_000;
+
+fn bulk(c: &mut Criterion) {
+    let bytes = (0..=u8::MAX).cycle().take(LEN * U32).collect::<Vec<u8>>();
+    let mut words = vec![0u32; LEN];
+    for (name, order) in [("be", Order::BE), ("le", Order::LE)] {
+        let mut group = c.benchmark_group(format!("u32-{name}"));
+        let view = Dyn::<&[u8]>::new(bytes.as_slice(), black_box(order));
+        group.bench_function("read-per-element", |b| {
+            b.iter(|| {
+                for (index, word) in words.iter_mut().enumerate() {
+                    *word = black_box(&view).u32(index * U32);
+                }
+            });
+        });
+        group.bench_function("read-into", |b| {
+            b.iter(|| black_box(&view).read_u32_into(0, &mut words));
+        });
+        let mut bytes = bytes.clone();
+        let mut view = Dyn::<&mut [u8]>::new(bytes.as_mut_slice(), black_box(order));
+        group.bench_function("set-per-element", |b| {
+            b.iter(|| {
+                for (index, &word) in words.iter().enumerate() {
+                    black_box(&mut view).set_u32(index * U32, word);
+                }
+            });
+        });
+        group.bench_function("set-from-slice", |b| {
+            b.iter(|| black_box(&mut view).set_u32_from_slice(0, &words));
+        });
+        group.finish();
+    }
+}
+
+mod group {
+    criterion::criterion_group!(benches, super::bulk);
+}
+criterion_main!(group::benches);
@@ -0,0 +1,39 @@
+//! Compares compile-time against runtime byte order selection.
+
+use criterion::{Criterion, black_box, criterion_main};
+use in_situ::{Be, Dyn, InSitu, Le, Order, U32};
+
+const LEN: usize = 4096;
+
+fn sum<T: InSitu>(view: &T) -> u32 {
+    (0..LEN)
+        .step_by(U32)
+        .fold(0, |sum, offset| sum.wrapping_add(view.u32(offset)))
+}
+
+fn order(c: &mut Criterion) {
+    let bytes = (0..=u8::MAX).cycle().take(LEN).collect::<Vec<u8>>();
+    let mut group = c.benchmark_group("u32");
+    group.bench_function("be", |b| {
+        let view = Be(bytes.as_slice());
+        b.iter(|| sum(black_box(&view)));
+    });
+    group.bench_function("dyn-be", |b| {
+        let view = Dyn::<&[u8]>::new(bytes.as_slice(), black_box(Order::BE));
+        b.iter(|| sum(black_box(&view)));
+    });
+    group.bench_function("le", |b| {
+        let view = Le::<&[u8]>(bytes.as_slice());
+        b.iter(|| sum(black_box(&view)));
+    });
+    group.bench_function("dyn-le", |b| {
+        let view = Dyn::<&[u8]>::new(bytes.as_slice(), black_box(Order::LE));
+        b.iter(|| sum(black_box(&view)));
+    });
+    group.finish();
+}
+
+mod group {
+    criterion::criterion_group!(benches, super::order);
+}
+criterion_main!(group::benches);
@@ -0,0 +1,608 @@
+//! Derive Macro for In Situ Endian-independent Bytes Access
+//!
+//! See [`InSitu`](macro@InSitu) and the [`in-situ`](https://docs.rs/in-situ) crate.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    Data, DeriveInput, Fields, Ident, LitInt, Path, Type, Visibility, parse_macro_input,
+    spanned::Spanned,
+};
+
+/// Derives a wrapper over any `Raw` type from a struct with annotated fields describing a layout.
+///
+/// The annotated struct is the layout, i.e., the decoded header. The wrapper named via
+/// `#[in_situ(wrapper = Name)]` stores the bytes and their `Order` and implements `InSitu` as well
+/// as `InSituMut`. It provides a `SIZE` constant, a getter and a setter for each field, and
+/// `load()` and `store()` to convert from and into the annotated struct.
+///
+/// # Struct Attributes
+///
+///   * `wrapper = Name`: Name of the wrapper to generate. Required.
+///   * `swap_size = N`: Value of `InSitu::swap_size()`, either zero or a power of two. Defaults to
+///     `0`.
+///   * `size = N`: Size of the layout in bytes. Defaults to the end of the last field. If given,
+///     fields exceeding it are rejected.
+///   * `volatile = Name`: Name of an optional register block wrapper to generate over `Volatile`
+///     bytes, e.g., memory-mapped device registers. It provides the same `SIZE` constant and
+///     accessors as the wrapper, but they access the fields volatilely via the `try_` accessors of
+///     `Volatile` and hence return a `Result`. Requires `swap_size = 0`.
+///
+/// # Field Attributes
+///
+///   * `offset = N`: Big-endian offset of the field in bytes. Required.
+///   * `ty = T`: Accessor type out of `bool`, `u8`, `u16`, `u24`, `u32`, `u64`, `u128`, `i8`,
+///     `i16`, `i24`, `i32`, `i64`, `i128`, `f32`, `f64`, `uint`, `uint128`, `int`, and `int128`.
+///     Defaults to the type of the field.
+///   * `word_size = N`: Word size of `uint`, `uint128`, `int`, and `int128` accessor types.
+///
+/// Overlapping fields and fields narrower than `swap_size` crossing a slice boundary are rejected
+/// at compile time. Fields of odd word sizes above two bytes like `u24` cannot be word-swapped, so
+/// the wrapper of such a layout rejects the word-swapped orders `Order::CDAB` and `Order::BADC` at
+/// run time.
+#[proc_macro_derive(InSitu, attributes(in_situ))]
+pub fn derive_in_situ(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Struct attributes.
+struct Layout {
+    wrapper: Ident,
+    volatile: Option<Ident>,
+    swap_size: usize,
+    size: Option<(usize, Span)>,
+}
+
+/// Field attributes and derived accessor properties.
+struct Field {
+    ident: Ident,
+    offset: usize,
+    word_size: usize,
+    getter: Ident,
+    setter: Ident,
+    value: TokenStream2,
+    variable: bool,
+    span: Span,
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "`InSitu` can only be derived for structs",
+        ));
+    };
+    let Fields::Named(named) = &data.fields else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "`InSitu` can only be derived for structs with named fields",
+        ));
+    };
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "`InSitu` cannot be derived for generic structs",
+        ));
+    }
+    let layout = parse_layout(input)?;
+    let fields = named
+        .named
+        .iter()
+        .map(parse_field)
+        .collect::<syn::Result<Vec<_>>>()?;
+    check(&layout, &fields)?;
+    let size = layout.size.map_or_else(
+        || {
+            fields
+                .iter()
+                .map(|field| field.offset + field.word_size)
+                .max()
+                .unwrap_or_default()
+        },
+        |(size, _span)| size,
+    );
+    Ok(generate(input, &layout, &fields, size))
+}
+
+fn parse_layout(input: &DeriveInput) -> syn::Result<Layout> {
+    let mut wrapper = None;
+    let mut volatile = None;
+    let mut swap_size: usize = 0;
+    let mut size = None;
+    for attr in input
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("in_situ"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("wrapper") {
+                wrapper = Some(meta.value()?.parse::<Ident>()?);
+            } else if meta.path.is_ident("volatile") {
+                volatile = Some(meta.value()?.parse::<Ident>()?);
+            } else if meta.path.is_ident("swap_size") {
+                let lit = meta.value()?.parse::<LitInt>()?;
+                swap_size = lit.base10_parse()?;
+                if swap_size != 0 && !swap_size.is_power_of_two() {
+                    return Err(syn::Error::new(
+                        lit.span(),
+                        "`swap_size` is neither zero nor a power of two",
+                    ));
+                }
+            } else if meta.path.is_ident("size") {
+                let lit = meta.value()?.parse::<LitInt>()?;
+                size = Some((lit.base10_parse()?, lit.span()));
+            } else {
+                return Err(meta.error("expected `wrapper`, `volatile`, `swap_size`, or `size`"));
+            }
+            Ok(())
+        })?;
+    }
+    let wrapper = wrapper.ok_or_else(|| {
+        syn::Error::new_spanned(
+            &input.ident,
+            "missing `#[in_situ(wrapper = Name)]` attribute",
+        )
+    })?;
+    if let Some(volatile) = &volatile
+        && swap_size != 0
+    {
+        return Err(syn::Error::new_spanned(
+            volatile,
+            "`volatile` requires `swap_size = 0`",
+        ));
+    }
+    Ok(Layout {
+        wrapper,
+        volatile,
+        swap_size,
+        size,
+    })
+}
+
+fn parse_field(field: &syn::Field) -> syn::Result<Field> {
+    let ident = field.ident.clone().expect("named field");
+    let mut offset = None;
+    let mut ty = None;
+    let mut word_size = None;
+    for attr in field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident("in_situ"))
+    {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("offset") {
+                offset = Some(meta.value()?.parse::<LitInt>()?.base10_parse::<usize>()?);
+            } else if meta.path.is_ident("ty") {
+                ty = Some(meta.value()?.parse::<Ident>()?);
+            } else if meta.path.is_ident("word_size") {
+                let lit = meta.value()?.parse::<LitInt>()?;
+                word_size = Some((lit.base10_parse::<usize>()?, lit.span()));
+            } else {
+                return Err(meta.error("expected `offset`, `ty`, or `word_size`"));
+            }
+            Ok(())
+        })?;
+    }
+    let offset = offset.ok_or_else(|| {
+        syn::Error::new_spanned(field, "missing `#[in_situ(offset = N)]` attribute")
+    })?;
+    let ty = match ty {
+        Some(ty) => ty,
+        None => match &field.ty {
+            Type::Path(path) if path.qself.is_none() => path.path.get_ident().cloned(),
+            _ => None,
+        }
+        .ok_or_else(|| {
+            syn::Error::new_spanned(&field.ty, "missing `#[in_situ(ty = T)]` attribute")
+        })?,
+    };
+    let (fixed, value, max) = match ty.to_string().as_str() {
+        "bool" => (Some(1), quote!(bool), 0),
+        "u8" => (Some(1), quote!(u8), 0),
+        "u16" => (Some(2), quote!(u16), 0),
+        "u24" => (Some(3), quote!(u32), 0),
+        "u32" => (Some(4), quote!(u32), 0),
+        "u64" => (Some(8), quote!(u64), 0),
+        "u128" => (Some(16), quote!(u128), 0),
+        "i8" => (Some(1), quote!(i8), 0),
+        "i16" => (Some(2), quote!(i16), 0),
+        "i24" => (Some(3), quote!(i32), 0),
+        "i32" => (Some(4), quote!(i32), 0),
+        "i64" => (Some(8), quote!(i64), 0),
+        "i128" => (Some(16), quote!(i128), 0),
+        "f32" => (Some(4), quote!(f32), 0),
+        "f64" => (Some(8), quote!(f64), 0),
+        "uint" => (None, quote!(u64), 8),
+        "uint128" => (None, quote!(u128), 16),
+        "int" => (None, quote!(i64), 8),
+        "int128" => (None, quote!(i128), 16),
+        _ => return Err(syn::Error::new_spanned(&ty, "unsupported accessor type")),
+    };
+    let word_size = match (fixed, word_size) {
+        (Some(fixed), None) => fixed,
+        (Some(_fixed), Some((_word_size, span))) => {
+            return Err(syn::Error::new(
+                span,
+                "`word_size` is only supported by `uint`, `uint128`, `int`, and `int128`",
+            ));
+        }
+        (None, Some((word_size, span))) => {
+            if word_size == 0 || word_size > max {
+                return Err(syn::Error::new(
+                    span,
+                    format!("`word_size` is not within 1 to {max} bytes"),
+                ));
+            }
+            word_size
+        }
+        (None, None) => {
+            return Err(syn::Error::new_spanned(
+                &ty,
+                "missing `#[in_situ(word_size = N)]` attribute",
+            ));
+        }
+    };
+    Ok(Field {
+        setter: format_ident!("set_{}", ty),
+        getter: ty,
+        ident,
+        offset,
+        word_size,
+        value,
+        variable: fixed.is_none(),
+        span: field.span(),
+    })
+}
+
+fn check(layout: &Layout, fields: &[Field]) -> syn::Result<()> {
+    for (index, field) in fields.iter().enumerate() {
+        let Some(end) = field.offset.checked_add(field.word_size) else {
+            return Err(syn::Error::new(field.span, "field offset overflows"));
+        };
+        let swap_size = layout.swap_size;
+        if field.word_size < swap_size && field.offset % swap_size + field.word_size > swap_size {
+            return Err(syn::Error::new(
+                field.span,
+                format!(
+                    "field `{}` at {}..{} crosses slice of {swap_size} bytes",
+                    field.ident, field.offset, end
+                ),
+            ));
+        }
+        if let Some((size, _span)) = layout.size
+            && end > size
+        {
+            return Err(syn::Error::new(
+                field.span,
+                format!(
+                    "field `{}` at {}..{} exceeds layout of {size} bytes",
+                    field.ident, field.offset, end
+                ),
+            ));
+        }
+        for other in &fields[..index] {
+            if field.offset < other.offset + other.word_size && other.offset < end {
+                return Err(syn::Error::new(
+                    field.span,
+                    format!(
+                        "field `{}` at {}..{} overlaps field `{}` at {}..{}",
+                        field.ident,
+                        field.offset,
+                        end,
+                        other.ident,
+                        other.offset,
+                        other.offset + other.word_size,
+                    ),
+                ));
+            }
+        }
+    }
+    Ok(())
+}
+
+fn generate(input: &DeriveInput, layout: &Layout, fields: &[Field], size: usize) -> TokenStream2 {
+    let krate: Path = syn::parse_quote!(::in_situ);
+    let vis: &Visibility = &input.vis;
+    let name = &input.ident;
+    let wrapper = &layout.wrapper;
+    let swap_size = layout.swap_size;
+    let wrapper_doc = format!("Wrapper of [`{name}`] layout over [`Raw`](in_situ::Raw) bytes.");
+    let word_swap = fields
+        .iter()
+        .map(|field| field.word_size)
+        .find(|&word_size| word_size > 2 && word_size % 2 != 0)
+        .map(|word_size| {
+            quote! {
+                if order.is_word_swapped() {
+                    return ::core::result::Result::Err(#krate::Error::WordSwap {
+                        word_size: #word_size,
+                    });
+                }
+            }
+        });
+    let volatile = layout
+        .volatile
+        .as_ref()
+        .map(|volatile| generate_volatile(input, volatile, fields, size, word_swap.as_ref()));
+    let getters = fields.iter().map(|field| getter(field, vis, &krate));
+    let setters = fields.iter().map(|field| setter(field, vis, &krate));
+    let idents = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+    let methods = idents
+        .iter()
+        .map(|ident| format_ident!("set_{}", ident))
+        .collect::<Vec<_>>();
+    quote! {
+        #[doc = #wrapper_doc]
+        #[derive(Debug, Clone, PartialEq, Eq)]
+        #vis struct #wrapper<R: #krate::Raw> {
+            raw: R,
+            order: #krate::Order,
+        }
+
+        impl<R: #krate::Raw> #wrapper<R> {
+            /// Size of the layout in bytes.
+            #vis const SIZE: usize = #size;
+
+            /// Wraps `raw` bytes of `order`.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::OutOfBounds`](in_situ::Error::OutOfBounds) if `raw` is shorter than
+            /// [`Self::SIZE`] and [`Error::WordSwap`](in_situ::Error::WordSwap) if `order` is
+            /// word-swapped but a field is of odd word size above two bytes.
+            #vis fn new(
+                raw: R,
+                order: #krate::Order,
+            ) -> ::core::result::Result<Self, #krate::Error> {
+                #word_swap
+                let length = raw.as_ref().len();
+                if length < Self::SIZE {
+                    return ::core::result::Result::Err(#krate::Error::OutOfBounds {
+                        offset: 0,
+                        word_size: Self::SIZE,
+                        length,
+                    });
+                }
+                ::core::result::Result::Ok(Self { raw, order })
+            }
+            /// Unwraps the raw bytes.
+            #vis fn into_raw(self) -> R {
+                self.raw
+            }
+            #[doc = concat!("Loads [`", stringify!(#name), "`] by getting all its fields.")]
+            #[must_use]
+            #vis fn load(&self) -> #name {
+                #name {
+                    #(#idents: self.#idents(),)*
+                }
+            }
+            #(#getters)*
+        }
+
+        impl<R: #krate::RawMut> #wrapper<R> {
+            #[doc = concat!("Stores [`", stringify!(#name), "`] by setting all its fields.")]
+            #vis fn store(&mut self, layout: &#name) {
+                #(self.#methods(layout.#idents);)*
+            }
+            #(#setters)*
+        }
+
+        impl<R: #krate::Raw> ::core::convert::AsRef<[u8]> for #wrapper<R> {
+            fn as_ref(&self) -> &[u8] {
+                self.raw.as_ref()
+            }
+        }
+
+        impl<R: #krate::RawMut> ::core::convert::AsMut<[u8]> for #wrapper<R> {
+            fn as_mut(&mut self) -> &mut [u8] {
+                self.raw.as_mut()
+            }
+        }
+
+        impl<R: #krate::Raw> #krate::InSitu for #wrapper<R> {
+            fn swap_size(&self) -> usize {
+                #swap_size
+            }
+            fn is_be(&self) -> bool {
+                self.order.is_be()
+            }
+            fn is_word_swapped(&self) -> bool {
+                self.order.is_word_swapped()
+            }
+        }
+
+        impl<R: #krate::RawMut> #krate::InSituMut for #wrapper<R> {}
+
+        #volatile
+    }
+}
+
+fn generate_volatile(
+    input: &DeriveInput,
+    volatile: &Ident,
+    fields: &[Field],
+    size: usize,
+    word_swap: Option<&TokenStream2>,
+) -> TokenStream2 {
+    let krate: Path = syn::parse_quote!(::in_situ);
+    let vis: &Visibility = &input.vis;
+    let name = &input.ident;
+    let volatile_doc =
+        format!("Register block of [`{name}`] layout over [`Volatile`](in_situ::Volatile) bytes.");
+    let load_doc = format!("Loads [`{name}`] by getting all its fields volatilely.");
+    let store_doc = format!("Stores [`{name}`] by setting all its fields volatilely.");
+    let word_swap = word_swap.map(|word_swap| {
+        quote! {
+            let order = volatile.order();
+            #word_swap
+        }
+    });
+    let getters = fields
+        .iter()
+        .map(|field| volatile_getter(field, vis, &krate));
+    let setters = fields
+        .iter()
+        .map(|field| volatile_setter(field, vis, &krate));
+    let idents = fields.iter().map(|field| &field.ident).collect::<Vec<_>>();
+    let methods = idents
+        .iter()
+        .map(|ident| format_ident!("set_{}", ident))
+        .collect::<Vec<_>>();
+    quote! {
+        #[doc = #volatile_doc]
+        #[derive(Debug)]
+        #vis struct #volatile<'a> {
+            volatile: #krate::Volatile<'a>,
+        }
+
+        impl<'a> #volatile<'a> {
+            /// Size of the layout in bytes.
+            #vis const SIZE: usize = #size;
+
+            /// Wraps `volatile` bytes.
+            ///
+            /// # Errors
+            ///
+            /// Returns [`Error::OutOfBounds`](in_situ::Error::OutOfBounds) if `volatile` is shorter
+            /// than [`Self::SIZE`] and [`Error::WordSwap`](in_situ::Error::WordSwap) if its order
+            /// is word-swapped but a field is of odd word size above two bytes.
+            #vis fn new(
+                volatile: #krate::Volatile<'a>,
+            ) -> ::core::result::Result<Self, #krate::Error> {
+                #word_swap
+                let length = volatile.len();
+                if length < Self::SIZE {
+                    return ::core::result::Result::Err(#krate::Error::OutOfBounds {
+                        offset: 0,
+                        word_size: Self::SIZE,
+                        length,
+                    });
+                }
+                ::core::result::Result::Ok(Self { volatile })
+            }
+            /// Unwraps the volatile bytes.
+            #vis fn into_volatile(self) -> #krate::Volatile<'a> {
+                self.volatile
+            }
+            #[doc = #load_doc]
+            ///
+            /// # Errors
+            ///
+            /// Returns the [`Error`](in_situ::Error) of the first field failing to get.
+            #vis fn load(&self) -> ::core::result::Result<#name, #krate::Error> {
+                ::core::result::Result::Ok(#name {
+                    #(#idents: self.#idents()?,)*
+                })
+            }
+            #[doc = #store_doc]
+            ///
+            /// # Errors
+            ///
+            /// Returns the [`Error`](in_situ::Error) of the first field failing to set, in which
+            /// case the preceding fields have been set.
+            #vis fn store(&mut self, layout: &#name) -> ::core::result::Result<(), #krate::Error> {
+                #(self.#methods(layout.#idents)?;)*
+                ::core::result::Result::Ok(())
+            }
+            #(#getters)*
+            #(#setters)*
+        }
+    }
+}
+
+fn getter(field: &Field, vis: &Visibility, krate: &Path) -> TokenStream2 {
+    let Field {
+        ident,
+        offset,
+        word_size,
+        getter,
+        value,
+        variable,
+        ..
+    } = field;
+    let doc = format!("Gets `{ident}` at big-endian offset `{offset}` endian-independently.");
+    let word_size = variable.then(|| quote!(, #word_size));
+    quote! {
+        #[doc = #doc]
+        #[must_use]
+        #vis fn #ident(&self) -> #value {
+            #krate::InSitu::#getter(self, #offset #word_size)
+        }
+    }
+}
+
+fn setter(field: &Field, vis: &Visibility, krate: &Path) -> TokenStream2 {
+    let Field {
+        ident,
+        offset,
+        word_size,
+        setter,
+        value,
+        variable,
+        ..
+    } = field;
+    let doc = format!("Sets `{ident}` at big-endian offset `{offset}` endian-independently.");
+    let method = format_ident!("set_{}", ident);
+    let word_size = variable.then(|| quote!(, #word_size));
+    quote! {
+        #[doc = #doc]
+        #vis fn #method(&mut self, value: #value) {
+            #krate::InSituMut::#setter(self, #offset, value #word_size);
+        }
+    }
+}
+
+fn volatile_getter(field: &Field, vis: &Visibility, krate: &Path) -> TokenStream2 {
+    let Field {
+        ident,
+        offset,
+        word_size,
+        getter,
+        value,
+        variable,
+        ..
+    } = field;
+    let doc = format!("Gets `{ident}` at big-endian offset `{offset}` volatilely.");
+    let method = format_ident!("try_{}", getter);
+    let word_size = variable.then(|| quote!(, #word_size));
+    quote! {
+        #[doc = #doc]
+        ///
+        /// # Errors
+        ///
+        /// Returns the [`Error`](in_situ::Error) of the volatile access.
+        #vis fn #ident(&self) -> ::core::result::Result<#value, #krate::Error> {
+            self.volatile.#method(#offset #word_size)
+        }
+    }
+}
+
+fn volatile_setter(field: &Field, vis: &Visibility, krate: &Path) -> TokenStream2 {
+    let Field {
+        ident,
+        offset,
+        word_size,
+        setter,
+        value,
+        variable,
+        ..
+    } = field;
+    let doc = format!("Sets `{ident}` at big-endian offset `{offset}` volatilely.");
+    let name = format_ident!("set_{}", ident);
+    let method = format_ident!("try_{}", setter);
+    let word_size = variable.then(|| quote!(, #word_size));
+    quote! {
+        #[doc = #doc]
+        ///
+        /// # Errors
+        ///
+        /// Returns the [`Error`](in_situ::Error) of the volatile access.
+        #vis fn #name(&mut self, value: #value) -> ::core::result::Result<(), #krate::Error> {
+            self.volatile.#method(#offset, value #word_size)
+        }
+    }
+}
@@ -0,0 +1,411 @@
+#[cfg(target_has_atomic = "64")]
+use super::swap_words_u64;
+use super::{
+    Error, Order,
+    error::{crossing, swap, within},
+};
+#[cfg(target_has_atomic = "32")]
+use core::sync::atomic::AtomicU32;
+#[cfg(target_has_atomic = "64")]
+use core::sync::atomic::AtomicU64;
+#[cfg(any(target_has_atomic = "32", target_has_atomic = "64"))]
+use core::sync::atomic::Ordering;
+use core::{cell::UnsafeCell, marker::PhantomData};
+
+/// Wraps bytes at a raw pointer shared between threads or processes in byte order selected at
+/// runtime, e.g., ring-buffer headers in shared memory.
+///
+/// Accesses the bytes atomically only, so its methods take `&self` and the wrapper is [`Sync`] to
+/// be shared between threads. Words are at big-endian offsets translated like [`InSitu::at()`] in
+/// slices of [`Self::swap_size()`], so offsets of layouts shared with [`InSitu`] views address the
+/// same bytes, and must be aligned to their size. Native views hand out [`AtomicU32`] and
+/// [`AtomicU64`] references, whereas the other methods swap the bytes of non-native views within
+/// compare-and-swap loops.
+///
+/// [`InSitu`]: crate::InSitu
+/// [`InSitu::at()`]: crate::InSitu::at
+#[derive(Debug)]
+pub struct Shared<'a> {
+    ptr: *mut u8,
+    len: usize,
+    order: Order,
+    swap_size: usize,
+    bytes: PhantomData<&'a [UnsafeCell<u8>]>,
+}
+
+// SAFETY: The bytes are accessed atomically only as required by `Shared::new()`.
+unsafe impl Send for Shared<'_> {}
+
+// SAFETY: The bytes are accessed atomically only as required by `Shared::new()`.
+unsafe impl Sync for Shared<'_> {}
+
+impl<'a> Shared<'a> {
+    /// Wraps `len` shared bytes at `ptr` of `order` and swap size `0`.
+    ///
+    /// # Safety
+    ///
+    /// The `ptr` must be non-null and valid for reads and writes of `len` bytes for lifetime `'a`,
+    /// during which the words accessed via the returned wrapper must be accessed atomically only,
+    /// also by other threads and processes, and never concurrently with atomic accesses of other
+    /// sizes overlapping them.
+    pub const unsafe fn new(ptr: *mut u8, len: usize, order: Order) -> Self {
+        Self {
+            ptr,
+            len,
+            order,
+            swap_size: 0,
+            bytes: PhantomData,
+        }
+    }
+    /// Wraps mutably borrowed `bytes` of `order` and swap size `0`, e.g., for sharing them between
+    /// scoped threads.
+    ///
+    /// # Safety
+    ///
+    /// The words accessed via the returned wrapper must never be accessed concurrently with atomic
+    /// accesses of other sizes overlapping them.
+    pub const unsafe fn from_slice(bytes: &'a mut [u8], order: Order) -> Self {
+        // SAFETY: The bytes are valid and exclusively borrowed for lifetime `'a`, so they are
+        // accessed via the returned wrapper only, and the sizes are consistent as required.
+        unsafe { Self::new(bytes.as_mut_ptr(), bytes.len(), order) }
+    }
+    /// Sets the swap size translating the offsets like [`InSitu::swap_size()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SwapSize`] if `swap_size` is neither zero nor a power of two.
+    ///
+    /// [`InSitu::swap_size()`]: crate::InSitu::swap_size
+    pub const fn with_swap_size(mut self, swap_size: usize) -> Result<Self, Error> {
+        if let Err(error) = swap(swap_size) {
+            return Err(error);
+        }
+        self.swap_size = swap_size;
+        Ok(self)
+    }
+    /// Swap size in bytes.
+    #[must_use]
+    pub const fn swap_size(&self) -> usize {
+        self.swap_size
+    }
+    /// Length in bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Tests if the length is zero.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Byte order.
+    #[must_use]
+    pub const fn order(&self) -> Order {
+        self.order
+    }
+    /// Tests if the byte order has the machine's native endianness.
+    #[must_use]
+    pub const fn is_native(&self) -> bool {
+        self.order.is_be() == cfg!(target_endian = "big") && !self.order.is_word_swapped()
+    }
+    /// Tries to translate big-endian `offset` of word with `word_size` like [`InSitu::try_at()`].
+    ///
+    /// [`InSitu::try_at()`]: crate::InSitu::try_at
+    #[cfg(any(target_has_atomic = "32", target_has_atomic = "64"))]
+    const fn at(&self, offset: usize, word_size: usize) -> Result<usize, Error> {
+        let swap_size = self.swap_size;
+        if self.order.is_be() || swap_size <= word_size {
+            return Ok(offset);
+        }
+        if let Err(error) = crossing(offset, word_size, swap_size) {
+            return Err(error);
+        }
+        let start = offset & (swap_size - 1);
+        Ok(offset - start + (swap_size - word_size - start))
+    }
+    /// Tries to get pointer to word of `T` at big-endian `offset` aligned for atomic `A`.
+    #[cfg(any(target_has_atomic = "32", target_has_atomic = "64"))]
+    fn word<T, A>(&self, offset: usize) -> Result<*mut T, Error> {
+        let offset = self.at(offset, size_of::<T>())?;
+        within(self.len, offset, size_of::<T>())?;
+        let word = self.ptr.wrapping_add(offset);
+        if word.cast::<A>().is_aligned() {
+            Ok(word.cast())
+        } else {
+            Err(Error::Misaligned {
+                offset,
+                align: align_of::<A>(),
+            })
+        }
+    }
+    /// Gets [`AtomicU32`] at big-endian `offset` for atomic access.
+    ///
+    /// Requires the view to be [`Self::is_native()`] and the word to be aligned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotNative`] if the view is not native, [`Error::Crossing`] if the word
+    /// crosses a slice boundary of a little-endian view, [`Error::OutOfBounds`] if the word exceeds
+    /// the bytes, and [`Error::Misaligned`] if the word is not aligned.
+    #[cfg(target_has_atomic = "32")]
+    pub fn atomic_u32(&self, offset: usize) -> Result<&AtomicU32, Error> {
+        if !self.is_native() {
+            return Err(Error::NotNative { order: self.order });
+        }
+        self.word_u32(offset)
+    }
+    /// Atomically loads [`u32`] at big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Crossing`] if the word crosses a slice boundary of a little-endian view,
+    /// [`Error::OutOfBounds`] if the word exceeds the bytes, and [`Error::Misaligned`] if the word
+    /// is not aligned.
+    #[cfg(target_has_atomic = "32")]
+    pub fn atomic_load_u32(&self, offset: usize, ordering: Ordering) -> Result<u32, Error> {
+        let word = self.word_u32(offset)?;
+        Ok(swap_u32(word.load(ordering), self.order))
+    }
+    /// Atomically stores `value` as [`u32`] at big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Crossing`] if the word crosses a slice boundary of a little-endian view,
+    /// [`Error::OutOfBounds`] if the word exceeds the bytes, and [`Error::Misaligned`] if the word
+    /// is not aligned.
+    #[cfg(target_has_atomic = "32")]
+    pub fn atomic_store_u32(
+        &self,
+        offset: usize,
+        value: u32,
+        ordering: Ordering,
+    ) -> Result<(), Error> {
+        let word = self.word_u32(offset)?;
+        word.store(swap_u32(value, self.order), ordering);
+        Ok(())
+    }
+    /// Atomically adds `value` to [`u32`] at big-endian `offset` endian-independently, wrapping
+    /// around on overflow, and returns the previous value.
+    ///
+    /// Uses [`AtomicU32::fetch_add()`] if the view is [`Self::is_native()`]. Otherwise, loads the
+    /// word, swaps it from the byte order of the view, adds `value`, swaps the sum back, and stores
+    /// it via [`AtomicU32::compare_exchange_weak()`] in a loop until no other access intervened in
+    /// between.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Crossing`] if the word crosses a slice boundary of a little-endian view,
+    /// [`Error::OutOfBounds`] if the word exceeds the bytes, and [`Error::Misaligned`] if the word
+    /// is not aligned.
+    #[cfg(target_has_atomic = "32")]
+    pub fn atomic_fetch_add_u32(
+        &self,
+        offset: usize,
+        value: u32,
+        ordering: Ordering,
+    ) -> Result<u32, Error> {
+        let order = self.order;
+        let word = self.word_u32(offset)?;
+        if self.is_native() {
+            return Ok(word.fetch_add(value, ordering));
+        }
+        let mut raw = word.load(Ordering::Relaxed);
+        loop {
+            let previous = swap_u32(raw, order);
+            let sum = swap_u32(previous.wrapping_add(value), order);
+            match word.compare_exchange_weak(raw, sum, ordering, Ordering::Relaxed) {
+                Ok(_) => return Ok(previous),
+                Err(current) => raw = current,
+            }
+        }
+    }
+    /// Atomically sets [`u32`] at big-endian `offset` to `new` endian-independently if it equals
+    /// `current` and returns the previous value, which equals `current` on success.
+    ///
+    /// Swaps `current` and `new` into the byte order of the view and compares and exchanges them
+    /// via [`AtomicU32::compare_exchange()`] with the `success` and `failure` memory orderings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Crossing`] if the word crosses a slice boundary of a little-endian view,
+    /// [`Error::OutOfBounds`] if the word exceeds the bytes, and [`Error::Misaligned`] if the word
+    /// is not aligned.
+    #[cfg(target_has_atomic = "32")]
+    pub fn atomic_compare_exchange_u32(
+        &self,
+        offset: usize,
+        current: u32,
+        new: u32,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Result<u32, u32>, Error> {
+        let order = self.order;
+        let word = self.word_u32(offset)?;
+        let current = swap_u32(current, order);
+        let new = swap_u32(new, order);
+        Ok(word
+            .compare_exchange(current, new, success, failure)
+            .map(|raw| swap_u32(raw, order))
+            .map_err(|raw| swap_u32(raw, order)))
+    }
+    /// Gets [`AtomicU64`] at big-endian `offset` for atomic access.
+    ///
+    /// Requires the view to be [`Self::is_native()`] and the word to be aligned.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotNative`] if the view is not native, [`Error::Crossing`] if the word
+    /// crosses a slice boundary of a little-endian view, [`Error::OutOfBounds`] if the word exceeds
+    /// the bytes, and [`Error::Misaligned`] if the word is not aligned.
+    #[cfg(target_has_atomic = "64")]
+    pub fn atomic_u64(&self, offset: usize) -> Result<&AtomicU64, Error> {
+        if !self.is_native() {
+            return Err(Error::NotNative { order: self.order });
+        }
+        self.word_u64(offset)
+    }
+    /// Atomically loads [`u64`] at big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Crossing`] if the word crosses a slice boundary of a little-endian view,
+    /// [`Error::OutOfBounds`] if the word exceeds the bytes, and [`Error::Misaligned`] if the word
+    /// is not aligned.
+    #[cfg(target_has_atomic = "64")]
+    pub fn atomic_load_u64(&self, offset: usize, ordering: Ordering) -> Result<u64, Error> {
+        let word = self.word_u64(offset)?;
+        Ok(swap_u64(word.load(ordering), self.order))
+    }
+    /// Atomically stores `value` as [`u64`] at big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Crossing`] if the word crosses a slice boundary of a little-endian view,
+    /// [`Error::OutOfBounds`] if the word exceeds the bytes, and [`Error::Misaligned`] if the word
+    /// is not aligned.
+    #[cfg(target_has_atomic = "64")]
+    pub fn atomic_store_u64(
+        &self,
+        offset: usize,
+        value: u64,
+        ordering: Ordering,
+    ) -> Result<(), Error> {
+        let word = self.word_u64(offset)?;
+        word.store(swap_u64(value, self.order), ordering);
+        Ok(())
+    }
+    /// Atomically adds `value` to [`u64`] at big-endian `offset` endian-independently, wrapping
+    /// around on overflow, and returns the previous value.
+    ///
+    /// Uses [`AtomicU64::fetch_add()`] if the view is [`Self::is_native()`]. Otherwise, loads the
+    /// word, swaps it from the byte order of the view, adds `value`, swaps the sum back, and stores
+    /// it via [`AtomicU64::compare_exchange_weak()`] in a loop until no other access intervened in
+    /// between.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Crossing`] if the word crosses a slice boundary of a little-endian view,
+    /// [`Error::OutOfBounds`] if the word exceeds the bytes, and [`Error::Misaligned`] if the word
+    /// is not aligned.
+    #[cfg(target_has_atomic = "64")]
+    pub fn atomic_fetch_add_u64(
+        &self,
+        offset: usize,
+        value: u64,
+        ordering: Ordering,
+    ) -> Result<u64, Error> {
+        let order = self.order;
+        let word = self.word_u64(offset)?;
+        if self.is_native() {
+            return Ok(word.fetch_add(value, ordering));
+        }
+        let mut raw = word.load(Ordering::Relaxed);
+        loop {
+            let previous = swap_u64(raw, order);
+            let sum = swap_u64(previous.wrapping_add(value), order);
+            match word.compare_exchange_weak(raw, sum, ordering, Ordering::Relaxed) {
+                Ok(_) => return Ok(previous),
+                Err(current) => raw = current,
+            }
+        }
+    }
+    /// Atomically sets [`u64`] at big-endian `offset` to `new` endian-independently if it equals
+    /// `current` and returns the previous value, which equals `current` on success.
+    ///
+    /// Swaps `current` and `new` into the byte order of the view and compares and exchanges them
+    /// via [`AtomicU64::compare_exchange()`] with the `success` and `failure` memory orderings.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Crossing`] if the word crosses a slice boundary of a little-endian view,
+    /// [`Error::OutOfBounds`] if the word exceeds the bytes, and [`Error::Misaligned`] if the word
+    /// is not aligned.
+    #[cfg(target_has_atomic = "64")]
+    pub fn atomic_compare_exchange_u64(
+        &self,
+        offset: usize,
+        current: u64,
+        new: u64,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<Result<u64, u64>, Error> {
+        let order = self.order;
+        let word = self.word_u64(offset)?;
+        let current = swap_u64(current, order);
+        let new = swap_u64(new, order);
+        Ok(word
+            .compare_exchange(current, new, success, failure)
+            .map(|raw| swap_u64(raw, order))
+            .map_err(|raw| swap_u64(raw, order)))
+    }
+    /// Gets [`AtomicU32`] at big-endian `offset` regardless of the byte order.
+    #[cfg(target_has_atomic = "32")]
+    fn word_u32(&self, offset: usize) -> Result<&AtomicU32, Error> {
+        let word = self.word::<u32, AtomicU32>(offset)?;
+        // SAFETY: The word is within the bytes valid for reads and writes for lifetime `'a`
+        // outliving the returned reference, aligned for `AtomicU32`, and accessed atomically only
+        // and never by overlapping atomics of other sizes as required by `Self::new()`, so the
+        // reference may be shared between threads like the wrapper.
+        Ok(unsafe { AtomicU32::from_ptr(word) })
+    }
+    /// Gets [`AtomicU64`] at big-endian `offset` regardless of the byte order.
+    #[cfg(target_has_atomic = "64")]
+    fn word_u64(&self, offset: usize) -> Result<&AtomicU64, Error> {
+        let word = self.word::<u64, AtomicU64>(offset)?;
+        // SAFETY: The word is within the bytes valid for reads and writes for lifetime `'a`
+        // outliving the returned reference, aligned for `AtomicU64`, and accessed atomically only
+        // and never by overlapping atomics of other sizes as required by `Self::new()`, so the
+        // reference may be shared between threads like the wrapper.
+        Ok(unsafe { AtomicU64::from_ptr(word) })
+    }
+}
+
+/// Converts `raw` native word into [`u32`] of `order` or vice versa.
+#[cfg(target_has_atomic = "32")]
+pub const fn swap_u32(raw: u32, order: Order) -> u32 {
+    let value = if order.is_be() {
+        u32::from_be(raw)
+    } else {
+        u32::from_le(raw)
+    };
+    if order.is_word_swapped() {
+        value.rotate_left(16)
+    } else {
+        value
+    }
+}
+
+/// Converts `raw` native word into [`u64`] of `order` or vice versa.
+#[cfg(target_has_atomic = "64")]
+pub const fn swap_u64(raw: u64, order: Order) -> u64 {
+    let value = if order.is_be() {
+        u64::from_be(raw)
+    } else {
+        u64::from_le(raw)
+    };
+    if order.is_word_swapped() {
+        swap_words_u64(value)
+    } else {
+        value
+    }
+}
@@ -0,0 +1,474 @@
+use super::{
+    Error, F32, F64, I8, I16, I24, I32, I64, I128, InSitu, InSituMut, InSituPrimitive, Order,
+    Packet, RawGrow, U8, U16, U24, U32, U64, U128,
+    error::{self, word, word_swap},
+    padding,
+};
+use alloc::vec::Vec;
+#[cfg(target_has_atomic = "ptr")]
+use core::sync::atomic::{AtomicUsize, Ordering};
+use core::{
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+};
+
+/// Append-only builder emitting bytes of [`Order`].
+///
+/// Grows [`RawGrow`] bytes like `Vec<u8>` or, with the `bytes` feature, `BytesMut` by appending
+/// words endian-independently. It implements [`InSitu`] and [`InSituMut`] to read and overwrite
+/// the bytes appended so far. Finishes into a [`Packet`] to read back the bytes.
+///
+/// Fields whose values are unknown when appending them, like lengths and offsets, are reserved as
+/// [`Placeholder`]s and back-patched later on. Placeholders and [`Marker`]s are tied to the builder
+/// issuing them, so they are rejected by any other builder except its clones.
+#[derive(Debug, Clone)]
+pub struct InSituBuilder<B: RawGrow = Vec<u8>> {
+    raw: B,
+    order: Order,
+    pending: Vec<usize>,
+    id: usize,
+}
+
+/// Reserved field of [`InSituPrimitive`] type `T` to be filled later on.
+///
+/// Returned by [`InSituBuilder::placeholder()`] and consumed by [`InSituBuilder::fill()`] or
+/// borrowed by [`InSituBuilder::fill_length()`] and [`InSituBuilder::fill_offset()`].
+#[derive(Debug, PartialEq, Eq, Hash)]
+#[must_use = "placeholders must be filled before finishing"]
+pub struct Placeholder<T: InSituPrimitive> {
+    offset: usize,
+    id: usize,
+    patch: PhantomData<fn(T)>,
+}
+
+impl<T: InSituPrimitive> Placeholder<T> {
+    /// Offset of the reserved field in bytes.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+/// Position marking the start of a section.
+///
+/// Returned by [`InSituBuilder::marker()`] and consumed by [`InSituBuilder::fill_length()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Marker {
+    offset: usize,
+    id: usize,
+}
+
+impl Marker {
+    /// Offset of the marked position in bytes.
+    #[must_use]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl<B: RawGrow> InSituBuilder<B> {
+    /// Creates builder appending to `raw` bytes of `order`.
+    pub fn new(raw: B, order: Order) -> Self {
+        Self {
+            raw,
+            order,
+            pending: Vec::new(),
+            id: id(),
+        }
+    }
+    /// Length of the bytes in bytes.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.raw.as_ref().len()
+    }
+    /// Whether the bytes are empty.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Finishes into [`Packet`] of [`Order`] and swap size `0`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Unfilled`] if a [`Placeholder`] has not been filled.
+    pub fn finish(self) -> Result<Packet<B>, Error> {
+        if let Some(&offset) = self.pending.iter().min() {
+            return Err(Error::Unfilled { offset });
+        }
+        Packet::new(self.raw, self.order, 0)
+    }
+    /// Marks the current position as start of a section.
+    #[must_use]
+    pub fn marker(&self) -> Marker {
+        Marker {
+            offset: self.len(),
+            id: self.id,
+        }
+    }
+    /// Appends zero bytes reserved as [`Placeholder`] of `T` to be filled later on.
+    pub fn placeholder<T: InSituPrimitive>(&mut self) -> Placeholder<T> {
+        let offset = self.grow(T::SIZE);
+        self.pending.push(offset);
+        Placeholder {
+            offset,
+            id: self.id,
+            patch: PhantomData,
+        }
+    }
+    /// Fills `placeholder` with `value` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Unissued`] if `placeholder` has not been issued by this builder.
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn fill<T: InSituPrimitive>(
+        &mut self,
+        placeholder: Placeholder<T>,
+        value: T,
+    ) -> Result<(), Error> {
+        self.patch(&placeholder, value)
+    }
+    /// Fills `placeholder` with the length in bytes of everything appended since `marker`.
+    ///
+    /// Borrows `placeholder` to keep it if the length does not fit into `T`. It is filled on
+    /// success, any further attempt to fill it returns [`Error::Unissued`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Unissued`] if `placeholder` or `marker` has not been issued by this builder
+    /// or if `placeholder` has already been filled and [`Error::Overflow`] if the length does not
+    /// fit into `T`, in which case the `placeholder` remains unfilled.
+    pub fn fill_length<T: InSituPrimitive + TryFrom<usize>>(
+        &mut self,
+        placeholder: &Placeholder<T>,
+        marker: Marker,
+    ) -> Result<(), Error> {
+        let length = self
+            .len()
+            .checked_sub(marker.offset)
+            .filter(|_length| marker.id == self.id)
+            .ok_or(Error::Unissued {
+                offset: marker.offset,
+            })?;
+        let length = T::try_from(length).map_err(|_| Error::Overflow { word_size: T::SIZE })?;
+        self.patch(placeholder, length)
+    }
+    /// Fills `placeholder` with the current position as offset of the section appended next.
+    ///
+    /// Borrows `placeholder` to keep it if the offset does not fit into `T`. It is filled on
+    /// success, any further attempt to fill it returns [`Error::Unissued`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Unissued`] if `placeholder` has not been issued by this builder or has
+    /// already been filled and [`Error::Overflow`] if the offset does not fit into `T`, in which
+    /// case the `placeholder` remains unfilled.
+    pub fn fill_offset<T: InSituPrimitive + TryFrom<usize>>(
+        &mut self,
+        placeholder: &Placeholder<T>,
+    ) -> Result<(), Error> {
+        let offset = T::try_from(self.len()).map_err(|_| Error::Overflow { word_size: T::SIZE })?;
+        self.patch(placeholder, offset)
+    }
+    /// Fills pending `placeholder` with `value` endian-independently.
+    fn patch<T: InSituPrimitive>(
+        &mut self,
+        placeholder: &Placeholder<T>,
+        value: T,
+    ) -> Result<(), Error> {
+        let offset = placeholder.offset;
+        let index = self
+            .pending
+            .iter()
+            .position(|&pending| pending == offset)
+            .filter(|_index| placeholder.id == self.id)
+            .ok_or(Error::Unissued { offset })?;
+        self.pending.swap_remove(index);
+        self.set(offset, value);
+        Ok(())
+    }
+    /// Appends `word_size` zero bytes and returns their offset.
+    fn grow(&mut self, word_size: usize) -> usize {
+        let offset = self.len();
+        self.raw.resize(offset + word_size, 0);
+        offset
+    }
+    /// Appends zero bytes up to the next length `align`ed to a power of two.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Align`] if `align` is not a power of two, in which case nothing is
+    /// appended.
+    pub fn pad_to(&mut self, align: usize) -> Result<(), Error> {
+        error::align(align)?;
+        self.grow(padding(self.len(), align));
+        Ok(())
+    }
+    /// Appends bytes.
+    pub fn put_bytes(&mut self, bytes: &[u8]) {
+        self.raw.extend_from_slice(bytes);
+    }
+    /// Appends [`&str`] as UTF-8.
+    pub fn put_utf8(&mut self, value: &str) {
+        self.put_bytes(value.as_bytes());
+    }
+    /// Appends [`bool`] endian-independently.
+    pub fn put_bool(&mut self, value: bool) {
+        let offset = self.grow(U8);
+        self.set_bool(offset, value);
+    }
+    /// Appends [`u8`] endian-independently.
+    pub fn put_u8(&mut self, value: u8) {
+        let offset = self.grow(U8);
+        self.set_u8(offset, value);
+    }
+    /// Appends [`u16`] endian-independently.
+    pub fn put_u16(&mut self, value: u16) {
+        let offset = self.grow(U16);
+        self.set_u16(offset, value);
+    }
+    /// Appends `u24` as [`u32`] endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`Order`] is word-swapped since the word is of odd size, see
+    /// [`Self::try_put_u24()`].
+    pub fn put_u24(&mut self, value: u32) {
+        let offset = self.grow(U24);
+        self.set_u24(offset, value);
+    }
+    /// Tries to append `u24` as [`u32`] endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSwap`] if the [`Order`] is word-swapped, in which case nothing is
+    /// appended.
+    pub fn try_put_u24(&mut self, value: u32) -> Result<(), Error> {
+        word_swap(U24, self.order.is_word_swapped())?;
+        self.put_u24(value);
+        Ok(())
+    }
+    /// Appends [`u32`] endian-independently.
+    pub fn put_u32(&mut self, value: u32) {
+        let offset = self.grow(U32);
+        self.set_u32(offset, value);
+    }
+    /// Appends [`u64`] endian-independently.
+    pub fn put_u64(&mut self, value: u64) {
+        let offset = self.grow(U64);
+        self.set_u64(offset, value);
+    }
+    /// Appends [`u128`] endian-independently.
+    pub fn put_u128(&mut self, value: u128) {
+        let offset = self.grow(U128);
+        self.set_u128(offset, value);
+    }
+    /// Appends unsigned integer of `word_size <= 8` endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_size` is zero or exceeds `8` or if the [`Order`] is word-swapped and
+    /// `word_size` is odd and above two bytes, see [`Self::try_put_uint()`].
+    pub fn put_uint(&mut self, value: u64, word_size: usize) {
+        let offset = self.grow(word_size);
+        self.set_uint(offset, value, word_size);
+    }
+    /// Tries to append unsigned integer of `word_size <= 8` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8` and [`Error::WordSwap`]
+    /// if the [`Order`] is word-swapped and `word_size` is odd, in which case nothing is appended.
+    pub fn try_put_uint(&mut self, value: u64, word_size: usize) -> Result<(), Error> {
+        word(word_size, U64)?;
+        word_swap(word_size, self.order.is_word_swapped())?;
+        self.put_uint(value, word_size);
+        Ok(())
+    }
+    /// Appends unsigned integer of `word_size <= 16` endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_size` is zero or exceeds `16` or if the [`Order`] is word-swapped and
+    /// `word_size` is odd and above two bytes, see [`Self::try_put_uint128()`].
+    pub fn put_uint128(&mut self, value: u128, word_size: usize) {
+        let offset = self.grow(word_size);
+        self.set_uint128(offset, value, word_size);
+    }
+    /// Tries to append unsigned integer of `word_size <= 16` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16` and [`Error::WordSwap`]
+    /// if the [`Order`] is word-swapped and `word_size` is odd, in which case nothing is appended.
+    pub fn try_put_uint128(&mut self, value: u128, word_size: usize) -> Result<(), Error> {
+        word(word_size, U128)?;
+        word_swap(word_size, self.order.is_word_swapped())?;
+        self.put_uint128(value, word_size);
+        Ok(())
+    }
+    /// Appends [`i8`] endian-independently.
+    pub fn put_i8(&mut self, value: i8) {
+        let offset = self.grow(I8);
+        self.set_i8(offset, value);
+    }
+    /// Appends [`i16`] endian-independently.
+    pub fn put_i16(&mut self, value: i16) {
+        let offset = self.grow(I16);
+        self.set_i16(offset, value);
+    }
+    /// Appends `i24` as [`i32`] endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the [`Order`] is word-swapped since the word is of odd size, see
+    /// [`Self::try_put_i24()`].
+    pub fn put_i24(&mut self, value: i32) {
+        let offset = self.grow(I24);
+        self.set_i24(offset, value);
+    }
+    /// Tries to append `i24` as [`i32`] endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSwap`] if the [`Order`] is word-swapped, in which case nothing is
+    /// appended.
+    pub fn try_put_i24(&mut self, value: i32) -> Result<(), Error> {
+        word_swap(I24, self.order.is_word_swapped())?;
+        self.put_i24(value);
+        Ok(())
+    }
+    /// Appends [`i32`] endian-independently.
+    pub fn put_i32(&mut self, value: i32) {
+        let offset = self.grow(I32);
+        self.set_i32(offset, value);
+    }
+    /// Appends [`i64`] endian-independently.
+    pub fn put_i64(&mut self, value: i64) {
+        let offset = self.grow(I64);
+        self.set_i64(offset, value);
+    }
+    /// Appends [`i128`] endian-independently.
+    pub fn put_i128(&mut self, value: i128) {
+        let offset = self.grow(I128);
+        self.set_i128(offset, value);
+    }
+    /// Appends signed integer of `word_size <= 8` endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_size` is zero or exceeds `8` or if the [`Order`] is word-swapped and
+    /// `word_size` is odd and above two bytes, see [`Self::try_put_int()`].
+    pub fn put_int(&mut self, value: i64, word_size: usize) {
+        let offset = self.grow(word_size);
+        self.set_int(offset, value, word_size);
+    }
+    /// Tries to append signed integer of `word_size <= 8` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8` and [`Error::WordSwap`]
+    /// if the [`Order`] is word-swapped and `word_size` is odd, in which case nothing is appended.
+    pub fn try_put_int(&mut self, value: i64, word_size: usize) -> Result<(), Error> {
+        word(word_size, I64)?;
+        word_swap(word_size, self.order.is_word_swapped())?;
+        self.put_int(value, word_size);
+        Ok(())
+    }
+    /// Appends signed integer of `word_size <= 16` endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_size` is zero or exceeds `16` or if the [`Order`] is word-swapped and
+    /// `word_size` is odd and above two bytes, see [`Self::try_put_int128()`].
+    pub fn put_int128(&mut self, value: i128, word_size: usize) {
+        let offset = self.grow(word_size);
+        self.set_int128(offset, value, word_size);
+    }
+    /// Tries to append signed integer of `word_size <= 16` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16` and [`Error::WordSwap`]
+    /// if the [`Order`] is word-swapped and `word_size` is odd, in which case nothing is appended.
+    pub fn try_put_int128(&mut self, value: i128, word_size: usize) -> Result<(), Error> {
+        word(word_size, I128)?;
+        word_swap(word_size, self.order.is_word_swapped())?;
+        self.put_int128(value, word_size);
+        Ok(())
+    }
+    /// Appends [`f32`] endian-independently.
+    pub fn put_f32(&mut self, value: f32) {
+        let offset = self.grow(F32);
+        self.set_f32(offset, value);
+    }
+    /// Appends [`f64`] endian-independently.
+    pub fn put_f64(&mut self, value: f64) {
+        let offset = self.grow(F64);
+        self.set_f64(offset, value);
+    }
+}
+
+impl<B: RawGrow> Default for InSituBuilder<B> {
+    fn default() -> Self {
+        Self::new(B::default(), Order::default())
+    }
+}
+
+impl<B: RawGrow> PartialEq for InSituBuilder<B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.raw == other.raw && self.order == other.order && self.pending == other.pending
+    }
+}
+
+impl<B: RawGrow> Eq for InSituBuilder<B> {}
+
+impl<B: RawGrow> Hash for InSituBuilder<B> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.raw.hash(state);
+        self.order.hash(state);
+        self.pending.hash(state);
+    }
+}
+
+impl<B: RawGrow> AsRef<[u8]> for InSituBuilder<B> {
+    fn as_ref(&self) -> &[u8] {
+        self.raw.as_ref()
+    }
+}
+
+impl<B: RawGrow> AsMut<[u8]> for InSituBuilder<B> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.raw.as_mut()
+    }
+}
+
+impl<B: RawGrow> InSitu for InSituBuilder<B> {
+    fn swap_size(&self) -> usize {
+        0
+    }
+    fn is_be(&self) -> bool {
+        self.order.is_be()
+    }
+    fn is_word_swapped(&self) -> bool {
+        self.order.is_word_swapped()
+    }
+    fn order(&self) -> Order {
+        self.order
+    }
+}
+
+impl<B: RawGrow> InSituMut for InSituBuilder<B> {}
+
+/// Gets unique identifier of a new builder if atomics are supported, otherwise zero.
+fn id() -> usize {
+    #[cfg(target_has_atomic = "ptr")]
+    {
+        static ID: AtomicUsize = AtomicUsize::new(1);
+        ID.fetch_add(1, Ordering::Relaxed)
+    }
+    #[cfg(not(target_has_atomic = "ptr"))]
+    {
+        0
+    }
+}
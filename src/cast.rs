@@ -0,0 +1,44 @@
+#[cfg(feature = "alloc")]
+use super::InSituCast;
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+use super::{Error, InSitu, error::bounds};
+#[cfg(feature = "alloc")]
+use core::slice;
+
+/// Checks if `view` is native and passes through offsets, i.e., if words of any size are laid out
+/// like fields of native structs.
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+pub fn native<Scope, V: InSitu<Scope> + ?Sized>(view: &V) -> Result<(), Error> {
+    if view.is_native() && (view.is_be() || view.swap_size() <= 1) {
+        Ok(())
+    } else {
+        Err(Error::NotNative {
+            order: view.order(),
+        })
+    }
+}
+
+/// Gets `size` bytes of `bytes` at `offset`.
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+pub fn bytes(bytes: &[u8], offset: usize, size: usize) -> Result<&[u8], Error> {
+    bounds(bytes, offset, size)?;
+    Ok(&bytes[offset..][..size])
+}
+
+/// Gets `size` bytes of `bytes` at `offset` mutably.
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+pub fn bytes_mut(bytes: &mut [u8], offset: usize, size: usize) -> Result<&mut [u8], Error> {
+    bounds(bytes, offset, size)?;
+    Ok(&mut bytes[offset..][..size])
+}
+
+/// Casts `bytes` into slice of `count` words of `T` if aligned.
+#[cfg(feature = "alloc")]
+pub fn slice<T: InSituCast>(bytes: &[u8], count: usize) -> Option<&[T]> {
+    debug_assert!(count * T::SIZE <= bytes.len(), "slice exceeds bytes");
+    let words = bytes.as_ptr().cast::<T>();
+    // SAFETY: The words are within `bytes`, aligned, and valid for any bit pattern.
+    words
+        .is_aligned()
+        .then(|| unsafe { slice::from_raw_parts(words, count) })
+}
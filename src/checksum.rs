@@ -0,0 +1,108 @@
+use super::Error;
+use byteorder::{BE, ByteOrder, LE};
+use core::ops::Range;
+
+/// Lookup table of CRC-32 with reflected polynomial `0xedb88320`.
+const CRC32: [u32; 256] = {
+    let mut table = [0; 256];
+    let mut index = 0;
+    while index < table.len() {
+        #[allow(clippy::cast_possible_truncation)]
+        let mut crc = index as u32;
+        let mut bit = 0;
+        while bit < 8 {
+            crc = if crc & 1 == 0 {
+                crc >> 1
+            } else {
+                (crc >> 1) ^ 0xedb8_8320
+            };
+            bit += 1;
+        }
+        table[index] = crc;
+        index += 1;
+    }
+    table
+};
+
+/// Largest number of bytes summed by Adler-32 before the sums need to be reduced.
+const NMAX: usize = 5552;
+
+/// Modulus of Adler-32.
+const BASE: u32 = 65521;
+
+/// Gets `range` of `bytes`.
+pub fn range(bytes: &[u8], range: Range<usize>) -> Result<&[u8], Error> {
+    bytes.get(range.clone()).ok_or_else(|| Error::OutOfBounds {
+        offset: range.start,
+        word_size: range.end.saturating_sub(range.start),
+        length: bytes.len(),
+    })
+}
+
+/// Adds `bytes` as 16-bit words of `is_be` byte order to ones' complement `sum`.
+///
+/// Pads an odd trailing byte with zero.
+pub fn ones(mut sum: u64, bytes: &[u8], is_be: bool) -> u64 {
+    let mut words = bytes.chunks_exact(2);
+    for word in &mut words {
+        let word = if is_be {
+            BE::read_u16(word)
+        } else {
+            LE::read_u16(word)
+        };
+        sum += u64::from(word);
+    }
+    if let &[byte] = words.remainder() {
+        sum += u64::from(if is_be {
+            u16::from(byte) << 8
+        } else {
+            u16::from(byte)
+        });
+    }
+    sum
+}
+
+/// Folds ones' complement `sum` into 16 bits.
+#[allow(clippy::cast_possible_truncation)]
+pub const fn fold(mut sum: u64) -> u16 {
+    while sum >> 16 != 0 {
+        sum = (sum & 0xffff) + (sum >> 16);
+    }
+    sum as u16
+}
+
+/// Computes CRC-32 (ISO-HDLC) of `bytes`.
+pub fn crc32(bytes: &[u8]) -> u32 {
+    !bytes.iter().fold(!0, |crc, &byte| {
+        CRC32[usize::from(crc.to_le_bytes()[0] ^ byte)] ^ (crc >> 8)
+    })
+}
+
+/// Computes non-reflected CRC-16 of `poly` with zero initial value and no final XOR of `bytes`.
+pub fn crc16(bytes: &[u8], poly: u16) -> u16 {
+    bytes.iter().fold(0, |mut crc, &byte| {
+        crc ^= u16::from(byte) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 == 0 {
+                crc << 1
+            } else {
+                (crc << 1) ^ poly
+            };
+        }
+        crc
+    })
+}
+
+/// Computes Adler-32 of `bytes`.
+pub fn adler32(bytes: &[u8]) -> u32 {
+    let (mut a, mut b) = (1, 0);
+    for chunk in bytes.chunks(NMAX) {
+        for &byte in chunk {
+            a += u32::from(byte);
+            b += a;
+        }
+        a %= BASE;
+        b %= BASE;
+    }
+    (b << 16) | a
+}
@@ -0,0 +1,313 @@
+#[cfg(feature = "bstr")]
+use super::BStr;
+use super::{
+    Error, F32, F64, I8, I16, I24, I32, I64, I128, InSitu, U8, U16, U24, U32, U64, U128,
+    error::{self, bounds},
+    padding,
+};
+use core::{fmt, marker::PhantomData};
+
+/// Sequential read cursor over an [`InSitu`] view.
+///
+/// Tracks a big-endian position advanced by each read, complementing the random access by absolute
+/// offsets for variable-length records. Reads are bounds-checked and report errors instead of
+/// panicking at the end of the view, in which case the position is not advanced.
+pub struct InSituCursor<'a, T: InSitu<Scope> + ?Sized, Scope = ()> {
+    view: &'a T,
+    position: usize,
+    scope: PhantomData<fn() -> Scope>,
+}
+
+impl<'a, T: InSitu<Scope> + ?Sized, Scope> InSituCursor<'a, T, Scope> {
+    /// Creates cursor over `view` at position `0`.
+    pub const fn new(view: &'a T) -> Self {
+        Self {
+            view,
+            position: 0,
+            scope: PhantomData,
+        }
+    }
+    /// Underlying view.
+    #[must_use]
+    pub const fn view(&self) -> &'a T {
+        self.view
+    }
+    /// Current position in bytes.
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+    /// Sets current `position` in bytes.
+    pub const fn set_position(&mut self, position: usize) {
+        self.position = position;
+    }
+    /// Remaining bytes after the current position.
+    #[must_use]
+    pub fn remaining(&self) -> usize {
+        self.view.as_ref().len().saturating_sub(self.position)
+    }
+    /// Skips `length` bytes.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the skipped bytes exceed the view.
+    pub fn skip(&mut self, length: usize) -> Result<(), Error> {
+        bounds(self.view.as_ref(), self.position, length)?;
+        self.position += length;
+        Ok(())
+    }
+    /// Skips padding bytes up to the next position `align`ed to a power of two.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Align`] if `align` is not a power of two and [`Error::OutOfBounds`] if the
+    /// skipped bytes exceed the view.
+    pub fn align_to(&mut self, align: usize) -> Result<(), Error> {
+        error::align(align)?;
+        self.skip(padding(self.position, align))
+    }
+    /// Reads `length` bytes at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the bytes exceed the view.
+    pub fn read_bytes(&mut self, length: usize) -> Result<&'a [u8], Error> {
+        bounds(self.view.as_ref(), self.position, length)?;
+        let bytes = &self.view.as_ref()[self.position..][..length];
+        self.position += length;
+        Ok(bytes)
+    }
+    /// Reads [`&str`] if UTF-8 of `length` bytes at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the string exceeds the view and [`Error::Utf8`] if the
+    /// bytes are not UTF-8.
+    pub fn read_utf8(&mut self, length: usize) -> Result<&'a str, Error> {
+        let value = self.view.try_utf8(self.position, length)?;
+        self.position += length;
+        Ok(value)
+    }
+    /// Reads [`BStr`] of `length` bytes at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the string exceeds the view.
+    #[cfg(feature = "bstr")]
+    pub fn read_bstr(&mut self, length: usize) -> Result<&'a BStr, Error> {
+        let value = self.view.try_bstr(self.position, length)?;
+        self.position += length;
+        Ok(value)
+    }
+    /// Reads [`bool`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_bool(&mut self) -> Result<bool, Error> {
+        let value = self.view.try_bool(self.position)?;
+        self.position += U8;
+        Ok(value)
+    }
+    /// Reads [`u8`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_u8(&mut self) -> Result<u8, Error> {
+        let value = self.view.try_u8(self.position)?;
+        self.position += U8;
+        Ok(value)
+    }
+    /// Reads [`u16`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_u16(&mut self) -> Result<u16, Error> {
+        let value = self.view.try_u16(self.position)?;
+        self.position += U16;
+        Ok(value)
+    }
+    /// Reads `u24` as [`u32`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_u24(&mut self) -> Result<u32, Error> {
+        let value = self.view.try_u24(self.position)?;
+        self.position += U24;
+        Ok(value)
+    }
+    /// Reads [`u32`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_u32(&mut self) -> Result<u32, Error> {
+        let value = self.view.try_u32(self.position)?;
+        self.position += U32;
+        Ok(value)
+    }
+    /// Reads [`u64`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_u64(&mut self) -> Result<u64, Error> {
+        let value = self.view.try_u64(self.position)?;
+        self.position += U64;
+        Ok(value)
+    }
+    /// Reads [`u128`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_u128(&mut self) -> Result<u128, Error> {
+        let value = self.view.try_u128(self.position)?;
+        self.position += U128;
+        Ok(value)
+    }
+    /// Reads unsigned integer of `word_size <= 8` at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8` and
+    /// [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_uint(&mut self, word_size: usize) -> Result<u64, Error> {
+        let value = self.view.try_uint(self.position, word_size)?;
+        self.position += word_size;
+        Ok(value)
+    }
+    /// Reads unsigned integer of `word_size <= 16` at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16` and
+    /// [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_uint128(&mut self, word_size: usize) -> Result<u128, Error> {
+        let value = self.view.try_uint128(self.position, word_size)?;
+        self.position += word_size;
+        Ok(value)
+    }
+    /// Reads [`i8`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_i8(&mut self) -> Result<i8, Error> {
+        let value = self.view.try_i8(self.position)?;
+        self.position += I8;
+        Ok(value)
+    }
+    /// Reads [`i16`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_i16(&mut self) -> Result<i16, Error> {
+        let value = self.view.try_i16(self.position)?;
+        self.position += I16;
+        Ok(value)
+    }
+    /// Reads `i24` as [`i32`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_i24(&mut self) -> Result<i32, Error> {
+        let value = self.view.try_i24(self.position)?;
+        self.position += I24;
+        Ok(value)
+    }
+    /// Reads [`i32`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_i32(&mut self) -> Result<i32, Error> {
+        let value = self.view.try_i32(self.position)?;
+        self.position += I32;
+        Ok(value)
+    }
+    /// Reads [`i64`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_i64(&mut self) -> Result<i64, Error> {
+        let value = self.view.try_i64(self.position)?;
+        self.position += I64;
+        Ok(value)
+    }
+    /// Reads [`i128`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_i128(&mut self) -> Result<i128, Error> {
+        let value = self.view.try_i128(self.position)?;
+        self.position += I128;
+        Ok(value)
+    }
+    /// Reads signed integer of `word_size <= 8` at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8` and
+    /// [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_int(&mut self, word_size: usize) -> Result<i64, Error> {
+        let value = self.view.try_int(self.position, word_size)?;
+        self.position += word_size;
+        Ok(value)
+    }
+    /// Reads signed integer of `word_size <= 16` at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16` and
+    /// [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_int128(&mut self, word_size: usize) -> Result<i128, Error> {
+        let value = self.view.try_int128(self.position, word_size)?;
+        self.position += word_size;
+        Ok(value)
+    }
+    /// Reads [`f32`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_f32(&mut self) -> Result<f32, Error> {
+        let value = self.view.try_f32(self.position)?;
+        self.position += F32;
+        Ok(value)
+    }
+    /// Reads [`f64`] at the position and advances it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the view.
+    pub fn read_f64(&mut self) -> Result<f64, Error> {
+        let value = self.view.try_f64(self.position)?;
+        self.position += F64;
+        Ok(value)
+    }
+}
+
+impl<T: InSitu<Scope> + ?Sized, Scope> Clone for InSituCursor<'_, T, Scope> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: InSitu<Scope> + ?Sized, Scope> Copy for InSituCursor<'_, T, Scope> {}
+
+impl<T: InSitu<Scope> + ?Sized, Scope> fmt::Debug for InSituCursor<'_, T, Scope> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InSituCursor")
+            .field("position", &self.position)
+            .field("remaining", &self.remaining())
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,90 @@
+use super::{Order, U16, U32};
+use byteorder::{BE, ByteOrder};
+
+/// Magic number entry of a table-driven byte order detection via [`Order::detect()`].
+///
+/// Associates the `bytes` expected at `offset` with their [`Order`] and a variant `tag`, e.g., the
+/// pcap magic `a1b2c3d4` with [`Order::BE`] and microsecond timestamps, whereas `d4c3b2a1` with
+/// [`Order::LE`] and microsecond timestamps, and `a1b23c4d` with [`Order::BE`] and nanosecond
+/// timestamps.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Magic<'a, T> {
+    /// Offset of the expected bytes.
+    pub offset: usize,
+    /// Expected bytes.
+    pub bytes: &'a [u8],
+    /// Byte order announced by the expected bytes.
+    pub order: Order,
+    /// Variant announced by the expected bytes.
+    pub tag: T,
+}
+
+impl<'a, T> Magic<'a, T> {
+    /// Creates entry associating `bytes` expected at `offset` with `order` and `tag`.
+    pub const fn new(offset: usize, bytes: &'a [u8], order: Order, tag: T) -> Self {
+        Self {
+            offset,
+            bytes,
+            order,
+            tag,
+        }
+    }
+    /// Whether `bytes` contain the expected bytes at the offset.
+    #[must_use]
+    pub fn matches(&self, bytes: &[u8]) -> bool {
+        bytes
+            .get(self.offset..)
+            .is_some_and(|bytes| bytes.starts_with(self.bytes))
+    }
+}
+
+impl Order {
+    /// Detects [`Order`] of `bytes` by comparing [`u16`] at `offset` with `expected` magic.
+    ///
+    /// Returns [`Order::BE`] if the magic is in big-endian byte order, [`Order::LE`] if it is
+    /// byte-swapped, and [`None`] otherwise or if the magic exceeds `bytes`. Magics invariant
+    /// under byte-swapping are detected as [`Order::BE`].
+    #[must_use]
+    pub fn detect_u16(bytes: &[u8], offset: usize, expected: u16) -> Option<Self> {
+        let found = BE::read_u16(bytes.get(offset..)?.get(..U16)?);
+        if found == expected {
+            Some(Self::BE)
+        } else if found == expected.swap_bytes() {
+            Some(Self::LE)
+        } else {
+            None
+        }
+    }
+    /// Detects [`Order`] of `bytes` by comparing [`u32`] at `offset` with `expected` magic.
+    ///
+    /// Returns [`Order::BE`] if the magic is in big-endian byte order, [`Order::LE`] if it is
+    /// byte-swapped, [`Order::CDAB`] if it is word-swapped, [`Order::BADC`] if it is byte-swapped
+    /// within words, and [`None`] otherwise or if the magic exceeds `bytes`. Magics invariant under
+    /// swapping are detected in the order of precedence listed.
+    #[must_use]
+    pub fn detect_u32(bytes: &[u8], offset: usize, expected: u32) -> Option<Self> {
+        let found = BE::read_u32(bytes.get(offset..)?.get(..U32)?);
+        let swapped = expected.rotate_left(16);
+        if found == expected {
+            Some(Self::BE)
+        } else if found == expected.swap_bytes() {
+            Some(Self::LE)
+        } else if found == swapped {
+            Some(Self::CDAB)
+        } else if found == swapped.swap_bytes() {
+            Some(Self::BADC)
+        } else {
+            None
+        }
+    }
+    /// Detects [`Order`] and variant tag of `bytes` via the first matching [`Magic`] of `table`.
+    ///
+    /// Returns [`None`] if no entry matches.
+    #[must_use]
+    pub fn detect<T: Copy>(bytes: &[u8], table: &[Magic<'_, T>]) -> Option<(Self, T)> {
+        table
+            .iter()
+            .find(|magic| magic.matches(bytes))
+            .map(|magic| (magic.order, magic.tag))
+    }
+}
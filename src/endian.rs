@@ -0,0 +1,132 @@
+use super::{InSitu, InSituMut, Order, Raw, RawMut, error::swap};
+
+/// Wraps [`Raw`] bytes in big-endian byte order selected at compile time.
+///
+/// Implements [`InSitu`] and [`InSituMut`] with a constant [`InSitu::is_be()`], so the byte order
+/// branch of each access is optimized away. The swap size is irrelevant for big-endian bytes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Be<R: Raw>(pub R);
+
+/// Wraps [`Raw`] bytes in little-endian byte order selected at compile time.
+///
+/// Implements [`InSitu`] and [`InSituMut`] with a constant [`InSitu::is_be()`], so the byte order
+/// branch of each access is optimized away. The swap size is given by `SWAP_SIZE`, which must be
+/// zero or a power of two.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Le<R: Raw, const SWAP_SIZE: usize = 0>(pub R);
+
+/// Wraps [`Raw`] bytes in byte order selected at runtime.
+///
+/// Implements [`InSitu`] and [`InSituMut`] by branching on the stored [`Order`] at each access. The
+/// swap size is given by `SWAP_SIZE`, which must be zero or a power of two.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Dyn<R: Raw, const SWAP_SIZE: usize = 0> {
+    raw: R,
+    order: Order,
+}
+
+impl<R: Raw> Be<R> {
+    /// Unwraps the raw bytes.
+    pub fn into_raw(self) -> R {
+        self.0
+    }
+}
+
+impl<R: Raw, const SWAP_SIZE: usize> Le<R, SWAP_SIZE> {
+    /// Unwraps the raw bytes.
+    pub fn into_raw(self) -> R {
+        self.0
+    }
+}
+
+impl<R: Raw, const SWAP_SIZE: usize> Dyn<R, SWAP_SIZE> {
+    /// Wraps `raw` bytes of `order`.
+    pub const fn new(raw: R, order: Order) -> Self {
+        Self { raw, order }
+    }
+    /// Unwraps the raw bytes.
+    pub fn into_raw(self) -> R {
+        self.raw
+    }
+}
+
+impl<R: Raw> AsRef<[u8]> for Be<R> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<R: Raw, const SWAP_SIZE: usize> AsRef<[u8]> for Le<R, SWAP_SIZE> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<R: Raw, const SWAP_SIZE: usize> AsRef<[u8]> for Dyn<R, SWAP_SIZE> {
+    fn as_ref(&self) -> &[u8] {
+        self.raw.as_ref()
+    }
+}
+
+impl<R: RawMut> AsMut<[u8]> for Be<R> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut()
+    }
+}
+
+impl<R: RawMut, const SWAP_SIZE: usize> AsMut<[u8]> for Le<R, SWAP_SIZE> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.0.as_mut()
+    }
+}
+
+impl<R: RawMut, const SWAP_SIZE: usize> AsMut<[u8]> for Dyn<R, SWAP_SIZE> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.raw.as_mut()
+    }
+}
+
+impl<R: Raw> InSitu for Be<R> {
+    #[inline]
+    fn swap_size(&self) -> usize {
+        0
+    }
+    #[inline]
+    fn is_be(&self) -> bool {
+        true
+    }
+}
+
+impl<R: Raw, const SWAP_SIZE: usize> InSitu for Le<R, SWAP_SIZE> {
+    #[inline]
+    fn swap_size(&self) -> usize {
+        const { assert!(swap(SWAP_SIZE).is_ok(), "invalid swap size") };
+        SWAP_SIZE
+    }
+    #[inline]
+    fn is_be(&self) -> bool {
+        false
+    }
+}
+
+impl<R: Raw, const SWAP_SIZE: usize> InSitu for Dyn<R, SWAP_SIZE> {
+    #[inline]
+    fn swap_size(&self) -> usize {
+        const { assert!(swap(SWAP_SIZE).is_ok(), "invalid swap size") };
+        SWAP_SIZE
+    }
+    #[inline]
+    fn is_be(&self) -> bool {
+        self.order.is_be()
+    }
+    #[inline]
+    fn is_word_swapped(&self) -> bool {
+        self.order.is_word_swapped()
+    }
+}
+
+impl<R: RawMut> InSituMut for Be<R> {}
+
+impl<R: RawMut, const SWAP_SIZE: usize> InSituMut for Le<R, SWAP_SIZE> {}
+
+impl<R: RawMut, const SWAP_SIZE: usize> InSituMut for Dyn<R, SWAP_SIZE> {}
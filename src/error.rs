@@ -0,0 +1,232 @@
+use super::Order;
+use core::{fmt, str::Utf8Error};
+
+/// Error type of the fallible methods of [`InSitu`] and [`InSituMut`] and its helper types.
+///
+/// [`InSitu`]: crate::InSitu
+/// [`InSituMut`]: crate::InSituMut
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// Word exceeds the slice.
+    OutOfBounds {
+        /// Offset of the word as translated by [`InSitu::at()`](crate::InSitu::at).
+        offset: usize,
+        /// Word size of the word in bytes.
+        word_size: usize,
+        /// Length of the slice in bytes.
+        length: usize,
+    },
+    /// Slice is not UTF-8.
+    Utf8(Utf8Error),
+    /// Slice is not UTF-16 due to an unpaired surrogate.
+    Utf16 {
+        /// Big-endian offset of the unpaired surrogate.
+        offset: usize,
+        /// Unpaired surrogate code unit.
+        surrogate: u16,
+    },
+    /// Word size is zero or exceeds the maximum word size of the method.
+    WordSize {
+        /// Word size of the word in bytes.
+        word_size: usize,
+        /// Maximum word size of the method in bytes.
+        max: usize,
+    },
+    /// Word of odd size above two bytes cannot be word-swapped.
+    WordSwap {
+        /// Word size of the word in bytes.
+        word_size: usize,
+    },
+    /// Value does not fit into word.
+    Overflow {
+        /// Word size of the word in bytes.
+        word_size: usize,
+    },
+    /// Swap size is neither zero nor a power of two.
+    SwapSize {
+        /// Swap size in bytes.
+        swap_size: usize,
+    },
+    /// Alignment is not a power of two.
+    Align {
+        /// Alignment in bytes.
+        align: usize,
+    },
+    /// Word crosses a boundary of a slice of swap size.
+    Crossing {
+        /// Big-endian offset of the word.
+        offset: usize,
+        /// Word size of the word in bytes.
+        word_size: usize,
+        /// Swap size in bytes.
+        swap_size: usize,
+    },
+    /// Placeholder has not been filled.
+    Unfilled {
+        /// Offset of the placeholder in bytes.
+        offset: usize,
+    },
+    /// Placeholder or marker has not been issued by the builder.
+    Unissued {
+        /// Offset of the placeholder or marker in bytes.
+        offset: usize,
+    },
+    /// Word is not aligned for atomic or volatile access.
+    Misaligned {
+        /// Offset of the word as translated by [`InSitu::at()`](crate::InSitu::at).
+        offset: usize,
+        /// Required alignment in bytes.
+        align: usize,
+    },
+    /// Byte order is not native.
+    NotNative {
+        /// Byte order.
+        order: Order,
+    },
+    /// Field cannot be accessed volatilely, e.g., a string.
+    NotVolatile {
+        /// Big-endian offset of the field.
+        offset: usize,
+    },
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfBounds {
+                offset,
+                word_size,
+                length,
+            } => write!(
+                f,
+                "word of {word_size} bytes at offset {offset} exceeds slice of {length} bytes"
+            ),
+            Self::Utf8(error) => write!(f, "invalid UTF-8: {error}"),
+            Self::Utf16 { offset, surrogate } => write!(
+                f,
+                "invalid UTF-16: unpaired surrogate {surrogate:#06x} at offset {offset}"
+            ),
+            Self::WordSize { word_size, max } => {
+                write!(f, "word size {word_size} is not within 1 to {max} bytes")
+            }
+            Self::WordSwap { word_size } => {
+                write!(f, "word of {word_size} bytes cannot be word-swapped")
+            }
+            Self::Overflow { word_size } => {
+                write!(f, "value does not fit into word of {word_size} bytes")
+            }
+            Self::SwapSize { swap_size } => {
+                write!(
+                    f,
+                    "swap size {swap_size} is neither zero nor a power of two"
+                )
+            }
+            Self::Align { align } => write!(f, "alignment {align} is not a power of two"),
+            Self::Crossing {
+                offset,
+                word_size,
+                swap_size,
+            } => write!(
+                f,
+                "word of {word_size} bytes at offset {offset} crosses slice of {swap_size} bytes"
+            ),
+            Self::Unfilled { offset } => write!(f, "placeholder at offset {offset} is unfilled"),
+            Self::Unissued { offset } => {
+                write!(
+                    f,
+                    "placeholder or marker at offset {offset} is not issued by builder"
+                )
+            }
+            Self::Misaligned { offset, align } => {
+                write!(f, "word at offset {offset} is not aligned to {align} bytes")
+            }
+            Self::NotNative { order } => write!(f, "byte order {order:?} is not native"),
+            Self::NotVolatile { offset } => {
+                write!(f, "field at offset {offset} cannot be accessed volatilely")
+            }
+        }
+    }
+}
+
+impl core::error::Error for Error {
+    fn source(&self) -> Option<&(dyn core::error::Error + 'static)> {
+        match self {
+            Self::Utf8(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<Utf8Error> for Error {
+    fn from(error: Utf8Error) -> Self {
+        Self::Utf8(error)
+    }
+}
+
+/// Checks if word of `word_size` at translated `offset` is within `bytes`.
+pub const fn bounds(bytes: &[u8], offset: usize, word_size: usize) -> Result<(), Error> {
+    within(bytes.len(), offset, word_size)
+}
+
+/// Checks if word of `word_size` at translated `offset` is within `length` bytes.
+pub const fn within(length: usize, offset: usize, word_size: usize) -> Result<(), Error> {
+    match offset.checked_add(word_size) {
+        Some(end) if end <= length => Ok(()),
+        _ => Err(Error::OutOfBounds {
+            offset,
+            word_size,
+            length,
+        }),
+    }
+}
+
+/// Checks if `word_size` is within `1..=max`.
+pub const fn word(word_size: usize, max: usize) -> Result<(), Error> {
+    if word_size == 0 || word_size > max {
+        Err(Error::WordSize { word_size, max })
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks if word of `word_size` can be word-swapped if `is_word_swapped`.
+pub const fn word_swap(word_size: usize, is_word_swapped: bool) -> Result<(), Error> {
+    if is_word_swapped && word_size > 2 && word_size % 2 != 0 {
+        Err(Error::WordSwap { word_size })
+    } else {
+        Ok(())
+    }
+}
+
+/// Checks if `swap_size` is zero or a power of two.
+pub const fn swap(swap_size: usize) -> Result<(), Error> {
+    if swap_size == 0 || swap_size.is_power_of_two() {
+        Ok(())
+    } else {
+        Err(Error::SwapSize { swap_size })
+    }
+}
+
+/// Checks if `align` is a power of two.
+pub const fn align(align: usize) -> Result<(), Error> {
+    if align.is_power_of_two() {
+        Ok(())
+    } else {
+        Err(Error::Align { align })
+    }
+}
+
+/// Checks if word of `word_size` at `offset` is within a slice of power-of-two `swap_size` unless
+/// the word is at least as wide as the slice.
+pub const fn crossing(offset: usize, word_size: usize, swap_size: usize) -> Result<(), Error> {
+    if word_size < swap_size && (offset & (swap_size - 1)) + word_size > swap_size {
+        Err(Error::Crossing {
+            offset,
+            word_size,
+            swap_size,
+        })
+    } else {
+        Ok(())
+    }
+}
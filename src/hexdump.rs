@@ -0,0 +1,108 @@
+use super::InSitu;
+use core::{fmt, marker::PhantomData, ops::Range};
+
+/// Bytes per line of [`Hexdump`].
+const LINE: usize = 16;
+
+/// Annotated hexdump of an [`InSitu`] view implementing [`fmt::Display`].
+///
+/// Returned by [`InSitu::hexdump()`]. Prints a header line with the [`Order`], swap size, and
+/// length of the view, followed by lines of the offset, the hexadecimal bytes, and their ASCII
+/// representation like `hexdump -C`, e.g.:
+///
+/// ```text
+/// BE, swap size 0, 20 bytes
+/// 00000000  45 00 05 dc 1c 46 40 00  40 06 00 00 c0 a8 00 01  |E....F@.@.......|
+/// 00000010  c0 a8 00 c7                                       |....|
+/// 0x0002..0x0004 total_len = 1500 (BE)
+/// ```
+///
+/// Optionally annotated with named field ranges, each followed by its value decoded as unsigned
+/// integer endian-independently if it is neither empty nor wider than 8 bytes.
+///
+/// [`Order`]: crate::Order
+pub struct Hexdump<'a, T: InSitu<Scope> + ?Sized, Scope = ()> {
+    view: &'a T,
+    fields: &'a [(&'a str, Range<usize>)],
+    scope: PhantomData<fn() -> Scope>,
+}
+
+impl<'a, T: InSitu<Scope> + ?Sized, Scope> Hexdump<'a, T, Scope> {
+    /// Creates hexdump of `view` without annotations.
+    pub const fn new(view: &'a T) -> Self {
+        Self {
+            view,
+            fields: &[],
+            scope: PhantomData,
+        }
+    }
+    /// Annotates named field ranges of big-endian offsets.
+    #[must_use]
+    pub const fn fields(mut self, fields: &'a [(&'a str, Range<usize>)]) -> Self {
+        self.fields = fields;
+        self
+    }
+}
+
+impl<T: InSitu<Scope> + ?Sized, Scope> fmt::Display for Hexdump<'_, T, Scope> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.view.as_ref();
+        let order = self.view.order();
+        writeln!(
+            f,
+            "{order:?}, swap size {}, {} bytes",
+            self.view.swap_size(),
+            bytes.len()
+        )?;
+        for (line, chunk) in bytes.chunks(LINE).enumerate() {
+            write!(f, "{:08x} ", line * LINE)?;
+            for index in 0..LINE {
+                if index % 8 == 0 {
+                    f.write_str(" ")?;
+                }
+                match chunk.get(index) {
+                    Some(byte) => write!(f, "{byte:02x} ")?,
+                    None => f.write_str("   ")?,
+                }
+            }
+            f.write_str(" |")?;
+            for &byte in chunk {
+                let ascii = if byte.is_ascii_graphic() || byte == b' ' {
+                    char::from(byte)
+                } else {
+                    '.'
+                };
+                write!(f, "{ascii}")?;
+            }
+            writeln!(f, "|")?;
+        }
+        for (name, range) in self.fields {
+            write!(f, "{:#06x}..{:#06x} {name}", range.start, range.end)?;
+            let word_size = range.end.saturating_sub(range.start);
+            if (1..=8).contains(&word_size) {
+                match self.view.try_uint(range.start, word_size) {
+                    Ok(value) => write!(f, " = {value} ({order:?})")?,
+                    Err(error) => write!(f, ": {error}")?,
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: InSitu<Scope> + ?Sized, Scope> Clone for Hexdump<'_, T, Scope> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: InSitu<Scope> + ?Sized, Scope> Copy for Hexdump<'_, T, Scope> {}
+
+impl<T: InSitu<Scope> + ?Sized, Scope> fmt::Debug for Hexdump<'_, T, Scope> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hexdump")
+            .field("fields", &self.fields)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,91 @@
+use core::{fmt, iter::FusedIterator};
+
+/// Iterator over a strided array of words in an [`InSitu`](crate::InSitu) view.
+///
+/// Returned by the `iter_*()` methods like [`InSitu::iter_u16()`](crate::InSitu::iter_u16) and
+/// [`InSitu::iter_u32_strided()`](crate::InSitu::iter_u32_strided). Gets each word lazily via the
+/// respective getter like [`InSitu::u16()`](crate::InSitu::u16), so it respects
+/// [`InSitu::at()`](crate::InSitu::at) and panics like the getter if a word exceeds the view.
+pub struct InSituIter<'a, V: ?Sized, T> {
+    view: &'a V,
+    get: fn(&V, usize) -> T,
+    offset: usize,
+    stride: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<'a, V: ?Sized, T> InSituIter<'a, V, T> {
+    /// Creates iterator over `count` words of `view` via `get` starting at big-endian `offset` and
+    /// advancing by `stride` bytes.
+    pub fn new(
+        view: &'a V,
+        get: fn(&V, usize) -> T,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> Self {
+        Self {
+            view,
+            get,
+            offset,
+            stride,
+            front: 0,
+            back: count,
+        }
+    }
+}
+
+impl<V: ?Sized, T> Iterator for InSituIter<'_, V, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        (self.front < self.back).then(|| {
+            let value = (self.get)(self.view, self.offset + self.front * self.stride);
+            self.front += 1;
+            value
+        })
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+    fn nth(&mut self, n: usize) -> Option<T> {
+        self.front = self.front.saturating_add(n).min(self.back);
+        self.next()
+    }
+}
+
+impl<V: ?Sized, T> DoubleEndedIterator for InSituIter<'_, V, T> {
+    fn next_back(&mut self) -> Option<T> {
+        (self.front < self.back).then(|| {
+            self.back -= 1;
+            (self.get)(self.view, self.offset + self.back * self.stride)
+        })
+    }
+    fn nth_back(&mut self, n: usize) -> Option<T> {
+        self.back = self.back.saturating_sub(n).max(self.front);
+        self.next_back()
+    }
+}
+
+impl<V: ?Sized, T> ExactSizeIterator for InSituIter<'_, V, T> {}
+
+impl<V: ?Sized, T> FusedIterator for InSituIter<'_, V, T> {}
+
+impl<V: ?Sized, T> Clone for InSituIter<'_, V, T> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<V: ?Sized, T> fmt::Debug for InSituIter<'_, V, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("InSituIter")
+            .field("offset", &self.offset)
+            .field("stride", &self.stride)
+            .field("front", &self.front)
+            .field("back", &self.back)
+            .finish_non_exhaustive()
+    }
+}
@@ -0,0 +1,608 @@
+#[cfg(feature = "serde")]
+use super::InSituMut;
+use super::{
+    Error, F32, F64, I8, I16, I24, I32, I64, I128, InSitu, U8, U16, U24, U32, U64, U128, Volatile,
+};
+#[cfg(feature = "serde")]
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::fmt;
+#[cfg(feature = "serde")]
+use core::marker::PhantomData;
+#[cfg(feature = "serde")]
+use serde::{
+    Deserialize, Deserializer, Serialize, Serializer,
+    de::{self, DeserializeSeed, MapAccess, Visitor},
+    ser::{self, SerializeMap},
+};
+
+/// Runtime descriptor of named fields evaluated against any [`InSitu`] view.
+///
+/// Complements the compile-time layouts of `#[derive(InSitu)]` for generic pretty printers,
+/// diffing, and dumps without writing per-format code. Fields are decoded by the bounds-checked
+/// getters of [`InSitu`] honoring its byte order and swap size. Nested layouts are evaluated
+/// relative to the offset of their field.
+///
+/// With the `serde` feature, a layout bound to a view via `Self::view()` serializes the decoded
+/// values and via `Self::view_mut()` deserializes values back into the view.
+///
+/// Register blocks are evaluated via [`Self::evaluate_volatile()`] against [`Volatile`] bytes
+/// instead, which supports all kinds but [`Kind::Utf8`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Layout<'a> {
+    /// Named fields.
+    pub fields: &'a [Field<'a>],
+}
+
+/// Named field of a [`Layout`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Field<'a> {
+    /// Name of the field.
+    pub name: &'a str,
+    /// Big-endian offset of the field relative to its layout.
+    pub offset: usize,
+    /// Kind of the field.
+    pub kind: Kind<'a>,
+}
+
+/// Kind of a [`Field`] selecting the [`InSitu`] getter to decode it with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind<'a> {
+    /// Decoded via [`InSitu::bool()`].
+    Bool,
+    /// Decoded via [`InSitu::u8()`].
+    U8,
+    /// Decoded via [`InSitu::u16()`].
+    U16,
+    /// Decoded via [`InSitu::u24()`].
+    U24,
+    /// Decoded via [`InSitu::u32()`].
+    U32,
+    /// Decoded via [`InSitu::u64()`].
+    U64,
+    /// Decoded via [`InSitu::u128()`].
+    U128,
+    /// Decoded via [`InSitu::uint()`] of word size.
+    Uint(usize),
+    /// Decoded via [`InSitu::uint128()`] of word size.
+    Uint128(usize),
+    /// Decoded via [`InSitu::i8()`].
+    I8,
+    /// Decoded via [`InSitu::i16()`].
+    I16,
+    /// Decoded via [`InSitu::i24()`].
+    I24,
+    /// Decoded via [`InSitu::i32()`].
+    I32,
+    /// Decoded via [`InSitu::i64()`].
+    I64,
+    /// Decoded via [`InSitu::i128()`].
+    I128,
+    /// Decoded via [`InSitu::int()`] of word size.
+    Int(usize),
+    /// Decoded via [`InSitu::int128()`] of word size.
+    Int128(usize),
+    /// Decoded via [`InSitu::f32()`].
+    F32,
+    /// Decoded via [`InSitu::f64()`].
+    F64,
+    /// Decoded via [`InSitu::utf8()`] of length.
+    Utf8(usize),
+    /// Decoded via nested layout at the offset of the field.
+    Layout(&'a Layout<'a>),
+}
+
+/// Decoded value of a [`Field`] as returned by [`Layout::evaluate()`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value<'a> {
+    /// Value of [`Kind::Bool`].
+    Bool(bool),
+    /// Value of [`Kind::U8`].
+    U8(u8),
+    /// Value of [`Kind::U16`].
+    U16(u16),
+    /// Value of [`Kind::U24`].
+    U24(u32),
+    /// Value of [`Kind::U32`].
+    U32(u32),
+    /// Value of [`Kind::U64`].
+    U64(u64),
+    /// Value of [`Kind::U128`].
+    U128(u128),
+    /// Value of [`Kind::Uint`].
+    Uint(u64),
+    /// Value of [`Kind::Uint128`].
+    Uint128(u128),
+    /// Value of [`Kind::I8`].
+    I8(i8),
+    /// Value of [`Kind::I16`].
+    I16(i16),
+    /// Value of [`Kind::I24`].
+    I24(i32),
+    /// Value of [`Kind::I32`].
+    I32(i32),
+    /// Value of [`Kind::I64`].
+    I64(i64),
+    /// Value of [`Kind::I128`].
+    I128(i128),
+    /// Value of [`Kind::Int`].
+    Int(i64),
+    /// Value of [`Kind::Int128`].
+    Int128(i128),
+    /// Value of [`Kind::F32`].
+    F32(f32),
+    /// Value of [`Kind::F64`].
+    F64(f64),
+    /// Value of [`Kind::Utf8`].
+    Utf8(&'a str),
+    /// Values of [`Kind::Layout`].
+    Layout(Vec<(&'a str, Self)>),
+}
+
+impl<'a> Layout<'a> {
+    /// Creates layout of named `fields`.
+    #[must_use]
+    pub const fn new(fields: &'a [Field<'a>]) -> Self {
+        Self { fields }
+    }
+    /// Size of the layout in bytes, i.e., the end of its last field.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        self.fields
+            .iter()
+            .map(|field| field.offset + field.kind.size())
+            .max()
+            .unwrap_or_default()
+    }
+    /// Evaluates the fields against `view` into their names and decoded values.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`Error`] of the first field failing to decode.
+    pub fn evaluate<'b, Scope, V: InSitu<Scope> + ?Sized>(
+        &self,
+        view: &'b V,
+    ) -> Result<Vec<(&'b str, Value<'b>)>, Error>
+    where
+        'a: 'b,
+    {
+        self.evaluate_at(view, 0)
+    }
+    /// Evaluates the fields against `volatile` bytes into their names and volatilely read values.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`Error`] of the first field failing to decode, in particular
+    /// [`Error::NotVolatile`] for [`Kind::Utf8`].
+    pub fn evaluate_volatile(
+        &self,
+        volatile: &Volatile<'_>,
+    ) -> Result<Vec<(&'a str, Value<'a>)>, Error> {
+        self.evaluate_volatile_at(volatile, 0)
+    }
+    /// Binds the fields to `view` serializing as map of their names to their decoded values.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub const fn view<'b, Scope, V: InSitu<Scope> + ?Sized>(
+        &'b self,
+        view: &'b V,
+    ) -> LayoutView<'b, V, Scope>
+    where
+        'a: 'b,
+    {
+        LayoutView {
+            layout: self,
+            view,
+            offset: 0,
+            scope: PhantomData,
+        }
+    }
+    /// Binds the fields to `view` deserializing a map of their names to their values into it.
+    #[cfg(feature = "serde")]
+    #[must_use]
+    pub const fn view_mut<'b, Scope, V: InSituMut<Scope> + ?Sized>(
+        &'b self,
+        view: &'b mut V,
+    ) -> LayoutViewMut<'b, V, Scope>
+    where
+        'a: 'b,
+    {
+        LayoutViewMut {
+            layout: self,
+            view,
+            offset: 0,
+            scope: PhantomData,
+        }
+    }
+    /// Evaluates the fields against `view` relative to big-endian `offset`.
+    fn evaluate_at<'b, Scope, V: InSitu<Scope> + ?Sized>(
+        &self,
+        view: &'b V,
+        offset: usize,
+    ) -> Result<Vec<(&'b str, Value<'b>)>, Error>
+    where
+        'a: 'b,
+    {
+        self.fields
+            .iter()
+            .map(|field| Ok((field.name, field.evaluate_at(view, offset)?)))
+            .collect()
+    }
+    /// Evaluates the fields against `volatile` bytes relative to big-endian `offset`.
+    fn evaluate_volatile_at(
+        &self,
+        volatile: &Volatile<'_>,
+        offset: usize,
+    ) -> Result<Vec<(&'a str, Value<'a>)>, Error> {
+        self.fields
+            .iter()
+            .map(|field| Ok((field.name, field.evaluate_volatile_at(volatile, offset)?)))
+            .collect()
+    }
+}
+
+impl<'a> Field<'a> {
+    /// Creates field of `name` at big-endian `offset` of `kind`.
+    #[must_use]
+    pub const fn new(name: &'a str, offset: usize, kind: Kind<'a>) -> Self {
+        Self { name, offset, kind }
+    }
+    /// Evaluates the field against `view` into its decoded value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`Error`] of the getter failing to decode the field.
+    pub fn evaluate<'b, Scope, V: InSitu<Scope> + ?Sized>(
+        &self,
+        view: &'b V,
+    ) -> Result<Value<'b>, Error>
+    where
+        'a: 'b,
+    {
+        self.evaluate_at(view, 0)
+    }
+    /// Evaluates the field against `volatile` bytes into its volatilely read value.
+    ///
+    /// # Errors
+    ///
+    /// Returns the [`Error`] of the getter failing to decode the field, in particular
+    /// [`Error::NotVolatile`] for [`Kind::Utf8`].
+    pub fn evaluate_volatile(&self, volatile: &Volatile<'_>) -> Result<Value<'a>, Error> {
+        self.evaluate_volatile_at(volatile, 0)
+    }
+    /// Evaluates the field against `view` relative to big-endian `offset`.
+    fn evaluate_at<'b, Scope, V: InSitu<Scope> + ?Sized>(
+        &self,
+        view: &'b V,
+        offset: usize,
+    ) -> Result<Value<'b>, Error>
+    where
+        'a: 'b,
+    {
+        let offset = offset + self.offset;
+        Ok(match self.kind {
+            Kind::Bool => Value::Bool(view.try_bool(offset)?),
+            Kind::U8 => Value::U8(view.try_u8(offset)?),
+            Kind::U16 => Value::U16(view.try_u16(offset)?),
+            Kind::U24 => Value::U24(view.try_u24(offset)?),
+            Kind::U32 => Value::U32(view.try_u32(offset)?),
+            Kind::U64 => Value::U64(view.try_u64(offset)?),
+            Kind::U128 => Value::U128(view.try_u128(offset)?),
+            Kind::Uint(word_size) => Value::Uint(view.try_uint(offset, word_size)?),
+            Kind::Uint128(word_size) => Value::Uint128(view.try_uint128(offset, word_size)?),
+            Kind::I8 => Value::I8(view.try_i8(offset)?),
+            Kind::I16 => Value::I16(view.try_i16(offset)?),
+            Kind::I24 => Value::I24(view.try_i24(offset)?),
+            Kind::I32 => Value::I32(view.try_i32(offset)?),
+            Kind::I64 => Value::I64(view.try_i64(offset)?),
+            Kind::I128 => Value::I128(view.try_i128(offset)?),
+            Kind::Int(word_size) => Value::Int(view.try_int(offset, word_size)?),
+            Kind::Int128(word_size) => Value::Int128(view.try_int128(offset, word_size)?),
+            Kind::F32 => Value::F32(view.try_f32(offset)?),
+            Kind::F64 => Value::F64(view.try_f64(offset)?),
+            Kind::Utf8(length) => Value::Utf8(view.try_utf8(offset, length)?),
+            Kind::Layout(layout) => Value::Layout(layout.evaluate_at(view, offset)?),
+        })
+    }
+    /// Evaluates the field against `volatile` bytes relative to big-endian `offset`.
+    fn evaluate_volatile_at(
+        &self,
+        volatile: &Volatile<'_>,
+        offset: usize,
+    ) -> Result<Value<'a>, Error> {
+        let offset = offset + self.offset;
+        Ok(match self.kind {
+            Kind::Bool => Value::Bool(volatile.try_bool(offset)?),
+            Kind::U8 => Value::U8(volatile.try_u8(offset)?),
+            Kind::U16 => Value::U16(volatile.try_u16(offset)?),
+            Kind::U24 => Value::U24(volatile.try_u24(offset)?),
+            Kind::U32 => Value::U32(volatile.try_u32(offset)?),
+            Kind::U64 => Value::U64(volatile.try_u64(offset)?),
+            Kind::U128 => Value::U128(volatile.try_u128(offset)?),
+            Kind::Uint(word_size) => Value::Uint(volatile.try_uint(offset, word_size)?),
+            Kind::Uint128(word_size) => Value::Uint128(volatile.try_uint128(offset, word_size)?),
+            Kind::I8 => Value::I8(volatile.try_i8(offset)?),
+            Kind::I16 => Value::I16(volatile.try_i16(offset)?),
+            Kind::I24 => Value::I24(volatile.try_i24(offset)?),
+            Kind::I32 => Value::I32(volatile.try_i32(offset)?),
+            Kind::I64 => Value::I64(volatile.try_i64(offset)?),
+            Kind::I128 => Value::I128(volatile.try_i128(offset)?),
+            Kind::Int(word_size) => Value::Int(volatile.try_int(offset, word_size)?),
+            Kind::Int128(word_size) => Value::Int128(volatile.try_int128(offset, word_size)?),
+            Kind::F32 => Value::F32(volatile.try_f32(offset)?),
+            Kind::F64 => Value::F64(volatile.try_f64(offset)?),
+            Kind::Utf8(_length) => return Err(Error::NotVolatile { offset }),
+            Kind::Layout(layout) => Value::Layout(layout.evaluate_volatile_at(volatile, offset)?),
+        })
+    }
+}
+
+impl Kind<'_> {
+    /// Size of the field in bytes.
+    #[must_use]
+    pub fn size(&self) -> usize {
+        match *self {
+            Self::Bool | Self::U8 => U8,
+            Self::U16 => U16,
+            Self::U24 => U24,
+            Self::U32 => U32,
+            Self::U64 => U64,
+            Self::U128 => U128,
+            Self::I8 => I8,
+            Self::I16 => I16,
+            Self::I24 => I24,
+            Self::I32 => I32,
+            Self::I64 => I64,
+            Self::I128 => I128,
+            Self::F32 => F32,
+            Self::F64 => F64,
+            Self::Uint(size)
+            | Self::Uint128(size)
+            | Self::Int(size)
+            | Self::Int128(size)
+            | Self::Utf8(size) => size,
+            Self::Layout(layout) => layout.size(),
+        }
+    }
+}
+
+impl fmt::Display for Value<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Bool(value) => value.fmt(f),
+            Self::U8(value) => value.fmt(f),
+            Self::U16(value) => value.fmt(f),
+            Self::U24(value) | Self::U32(value) => value.fmt(f),
+            Self::U64(value) | Self::Uint(value) => value.fmt(f),
+            Self::U128(value) | Self::Uint128(value) => value.fmt(f),
+            Self::I8(value) => value.fmt(f),
+            Self::I16(value) => value.fmt(f),
+            Self::I24(value) | Self::I32(value) => value.fmt(f),
+            Self::I64(value) | Self::Int(value) => value.fmt(f),
+            Self::I128(value) | Self::Int128(value) => value.fmt(f),
+            Self::F32(value) => value.fmt(f),
+            Self::F64(value) => value.fmt(f),
+            Self::Utf8(value) => write!(f, "{value:?}"),
+            Self::Layout(values) => {
+                f.write_str("{")?;
+                for (index, (name, value)) in values.iter().enumerate() {
+                    let separator = if index == 0 { " " } else { ", " };
+                    write!(f, "{separator}{name}: {value}")?;
+                }
+                f.write_str(if values.is_empty() { "}" } else { " }" })
+            }
+        }
+    }
+}
+
+/// [`Layout`] bound to an [`InSitu`] view serializing as map of field names to decoded values.
+///
+/// Returned by [`Layout::view()`]. Nested layouts serialize as nested maps and strings without
+/// their trailing NUL padding. Fails to serialize with the [`Error`] of the first field failing to
+/// decode.
+#[cfg(feature = "serde")]
+pub struct LayoutView<'a, V: InSitu<Scope> + ?Sized, Scope = ()> {
+    layout: &'a Layout<'a>,
+    view: &'a V,
+    offset: usize,
+    scope: PhantomData<fn() -> Scope>,
+}
+
+/// [`Layout`] bound to an [`InSituMut`] view deserializing a map of field names to values into it.
+///
+/// Returned by [`Layout::view_mut()`] and implementing [`DeserializeSeed`]. Nested layouts
+/// deserialize from nested maps. Fields missing in the map are left untouched, whereas unknown and
+/// duplicate field names are rejected. Values are set via the checked setters, rejecting integers
+/// exceeding their word size as well as strings exceeding their length, whereas shorter strings are
+/// padded with NUL.
+#[cfg(feature = "serde")]
+pub struct LayoutViewMut<'a, V: InSituMut<Scope> + ?Sized, Scope = ()> {
+    layout: &'a Layout<'a>,
+    view: &'a mut V,
+    offset: usize,
+    scope: PhantomData<fn() -> Scope>,
+}
+
+#[cfg(feature = "serde")]
+impl<V: InSitu<Scope> + ?Sized, Scope> Serialize for LayoutView<'_, V, Scope> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(self.layout.fields.len()))?;
+        for field in self.layout.fields {
+            if let Kind::Layout(layout) = field.kind {
+                let nested = LayoutView {
+                    layout,
+                    view: self.view,
+                    offset: self.offset + field.offset,
+                    scope: PhantomData,
+                };
+                map.serialize_entry(field.name, &nested)?;
+            } else {
+                let value = field
+                    .evaluate_at(self.view, self.offset)
+                    .map_err(ser::Error::custom)?;
+                map.serialize_entry(field.name, &value)?;
+            }
+        }
+        map.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for Value<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match *self {
+            Self::Bool(value) => serializer.serialize_bool(value),
+            Self::U8(value) => serializer.serialize_u8(value),
+            Self::U16(value) => serializer.serialize_u16(value),
+            Self::U24(value) | Self::U32(value) => serializer.serialize_u32(value),
+            Self::U64(value) | Self::Uint(value) => serializer.serialize_u64(value),
+            Self::U128(value) | Self::Uint128(value) => serializer.serialize_u128(value),
+            Self::I8(value) => serializer.serialize_i8(value),
+            Self::I16(value) => serializer.serialize_i16(value),
+            Self::I24(value) | Self::I32(value) => serializer.serialize_i32(value),
+            Self::I64(value) | Self::Int(value) => serializer.serialize_i64(value),
+            Self::I128(value) | Self::Int128(value) => serializer.serialize_i128(value),
+            Self::F32(value) => serializer.serialize_f32(value),
+            Self::F64(value) => serializer.serialize_f64(value),
+            Self::Utf8(value) => serializer.serialize_str(value.trim_end_matches('\0')),
+            Self::Layout(ref values) => {
+                let mut map = serializer.serialize_map(Some(values.len()))?;
+                for (name, value) in values {
+                    map.serialize_entry(name, value)?;
+                }
+                map.end()
+            }
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, V: InSituMut<Scope> + ?Sized, Scope> DeserializeSeed<'de>
+    for LayoutViewMut<'_, V, Scope>
+{
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, V: InSituMut<Scope> + ?Sized, Scope> Visitor<'de> for LayoutViewMut<'_, V, Scope> {
+    type Value = ();
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a map of field names to values")
+    }
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<(), A::Error> {
+        let mut seen = Vec::new();
+        while let Some(index) = map.next_key_seed(FieldName(self.layout.fields))? {
+            if seen.contains(&index) {
+                let name = self.layout.fields[index].name;
+                return Err(de::Error::custom(format_args!("duplicate field `{name}`")));
+            }
+            seen.push(index);
+            map.next_value_seed(FieldMut {
+                field: &self.layout.fields[index],
+                view: &mut *self.view,
+                offset: self.offset,
+                scope: PhantomData,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Seed deserializing a field name into the index of its [`Field`].
+#[cfg(feature = "serde")]
+struct FieldName<'a>(&'a [Field<'a>]);
+
+#[cfg(feature = "serde")]
+impl<'de> DeserializeSeed<'de> for FieldName<'_> {
+    type Value = usize;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<usize, D::Error> {
+        deserializer.deserialize_str(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Visitor<'_> for FieldName<'_> {
+    type Value = usize;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a field name")
+    }
+    fn visit_str<E: de::Error>(self, name: &str) -> Result<usize, E> {
+        self.0
+            .iter()
+            .position(|field| field.name == name)
+            .ok_or_else(|| E::custom(format_args!("unknown field `{name}`")))
+    }
+}
+
+/// Seed deserializing the value of a [`Field`] into an [`InSituMut`] view.
+#[cfg(feature = "serde")]
+struct FieldMut<'a, V: InSituMut<Scope> + ?Sized, Scope> {
+    field: &'a Field<'a>,
+    view: &'a mut V,
+    offset: usize,
+    scope: PhantomData<fn() -> Scope>,
+}
+
+#[cfg(feature = "serde")]
+impl<'de, V: InSituMut<Scope> + ?Sized, Scope> DeserializeSeed<'de> for FieldMut<'_, V, Scope> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        let view = self.view;
+        let offset = self.offset + self.field.offset;
+        let result = match self.field.kind {
+            Kind::Bool => view.try_set_bool(offset, bool::deserialize(deserializer)?),
+            Kind::U8 => view.try_set_u8(offset, u8::deserialize(deserializer)?),
+            Kind::U16 => view.try_set_u16(offset, u16::deserialize(deserializer)?),
+            Kind::U24 => view.set_u24_checked(offset, u32::deserialize(deserializer)?),
+            Kind::U32 => view.try_set_u32(offset, u32::deserialize(deserializer)?),
+            Kind::U64 => view.try_set_u64(offset, u64::deserialize(deserializer)?),
+            Kind::U128 => view.try_set_u128(offset, u128::deserialize(deserializer)?),
+            Kind::Uint(word_size) => {
+                view.set_uint_checked(offset, u64::deserialize(deserializer)?, word_size)
+            }
+            Kind::Uint128(word_size) => {
+                view.set_uint128_checked(offset, u128::deserialize(deserializer)?, word_size)
+            }
+            Kind::I8 => view.try_set_i8(offset, i8::deserialize(deserializer)?),
+            Kind::I16 => view.try_set_i16(offset, i16::deserialize(deserializer)?),
+            Kind::I24 => view.set_i24_checked(offset, i32::deserialize(deserializer)?),
+            Kind::I32 => view.try_set_i32(offset, i32::deserialize(deserializer)?),
+            Kind::I64 => view.try_set_i64(offset, i64::deserialize(deserializer)?),
+            Kind::I128 => view.try_set_i128(offset, i128::deserialize(deserializer)?),
+            Kind::Int(word_size) => {
+                view.set_int_checked(offset, i64::deserialize(deserializer)?, word_size)
+            }
+            Kind::Int128(word_size) => {
+                view.set_int128_checked(offset, i128::deserialize(deserializer)?, word_size)
+            }
+            Kind::F32 => view.try_set_f32(offset, f32::deserialize(deserializer)?),
+            Kind::F64 => view.try_set_f64(offset, f64::deserialize(deserializer)?),
+            Kind::Utf8(length) => {
+                let value = String::deserialize(deserializer)?;
+                if value.len() > length {
+                    Err(Error::Overflow { word_size: length })
+                } else {
+                    view.set_padded_utf8(offset, length, &value, 0).map(drop)
+                }
+            }
+            Kind::Layout(layout) => {
+                let nested = LayoutViewMut {
+                    layout,
+                    view,
+                    offset,
+                    scope: PhantomData,
+                };
+                return nested.deserialize(deserializer);
+            }
+        };
+        result.map_err(de::Error::custom)
+    }
+}
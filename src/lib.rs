@@ -2,19 +2,86 @@
 //!
 //! # Feature Gates
 //!
-//!   * `bytes`: For abstracting `Bytes` and `BytesMut`.
+//!   * `std`: For implementing `std` extensions of dependencies. Enabled by default.
+//!   * `alloc`: For implementing [`Raw`] for `Vec<u8>`, for `InSituBuilder`, for getters of owned
+//!     strings, and for evaluating `Layout`. Enabled by `std`.
+//!   * `bytes`: For abstracting `Bytes` and `BytesMut`. Enables `alloc`.
 //!   * `bstr`: For complementing [`InSitu::utf8()`] with `InSitu::bstr()`.
+//!   * `derive`: For deriving wrappers from annotated layouts via `#[derive(InSitu)]`.
+//!   * `serde`: For serializing views through `Layout` and for deriving `Serialize` and
+//!     `Deserialize` of [`Order`] and [`Take`]. Enables `alloc`.
+//!   * `bytemuck`: For viewing `#[repr(C)]` structs in place via `InSitu::bytemuck_ref()`.
+//!   * `zerocopy`: For viewing `#[repr(C)]` structs in place via `InSitu::zerocopy_ref()`.
+
+#![no_std]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+#[cfg(feature = "std")]
+extern crate std;
 
 #[cfg(feature = "bstr")]
 pub use bstr;
 #[cfg(feature = "bstr")]
 use bstr::BStr;
+#[cfg(feature = "bytemuck")]
+pub use bytemuck;
 pub use byteorder;
 #[cfg(feature = "bytes")]
 pub use bytes;
+#[cfg(feature = "derive")]
+pub use in_situ_derive::InSitu;
+#[cfg(feature = "serde")]
+pub use serde;
+#[cfg(feature = "zerocopy")]
+pub use zerocopy;
+
+mod atomic;
+#[cfg(feature = "alloc")]
+mod builder;
+#[cfg(any(feature = "alloc", feature = "bytemuck", feature = "zerocopy"))]
+mod cast;
+mod checksum;
+mod cursor;
+mod detect;
+mod endian;
+mod error;
+mod hexdump;
+mod iter;
+#[cfg(feature = "alloc")]
+mod layout;
+mod packet;
+mod primitive;
+mod text;
+mod volatile;
+
+pub use atomic::Shared;
+#[cfg(feature = "alloc")]
+pub use builder::{InSituBuilder, Marker, Placeholder};
+pub use cursor::InSituCursor;
+pub use detect::Magic;
+pub use endian::{Be, Dyn, Le};
+pub use error::Error;
+pub use hexdump::Hexdump;
+pub use iter::InSituIter;
+#[cfg(feature = "alloc")]
+pub use layout::{Field, Kind, Layout, Value};
+#[cfg(feature = "serde")]
+pub use layout::{LayoutView, LayoutViewMut};
+pub use packet::Packet;
+pub use primitive::{InSituCast, InSituPrimitive};
+pub use volatile::Volatile;
 
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, string::String, vec::Vec};
+#[cfg(all(feature = "alloc", feature = "bstr"))]
+use bstr::BString;
 use byteorder::{BE, ByteOrder, LE, NativeEndian};
-use std::{fmt::Debug, hash::Hash, mem, str::Utf8Error};
+use core::{
+    char::DecodeUtf16, fmt::Debug, hash::Hash, iter::repeat, mem, ops::Range, str::Utf8Error,
+};
+use error::{bounds, crossing, swap, word, word_swap};
+use text::{padded, prefixed, terminated, trimmed, truncated};
 
 /// Size of [`u8`] in bytes.
 pub const U8: usize = 1;
@@ -67,14 +134,74 @@ pub fn aligned(offset: usize, align: usize) -> usize {
     aligned
 }
 
+/// Passes through `word_size` of `0 < word_size <= max` bytes.
+///
+/// Panics if the word size is zero or exceeds `max`, see [`Error::WordSize`].
+const fn sized(word_size: usize, max: usize) -> usize {
+    if let Err(Error::WordSize { .. }) = word(word_size, max) {
+        panic!("word size is zero or exceeds maximum");
+    }
+    word_size
+}
+
+/// Gets maximum of unsigned integer of `word_size <= 16` bytes.
+const fn umax(word_size: usize) -> u128 {
+    u128::MAX >> ((U128 - word_size) * 8)
+}
+
+/// Gets maximum of signed integer of `word_size <= 16` bytes.
+#[allow(clippy::cast_possible_wrap)]
+const fn imax(word_size: usize) -> i128 {
+    (umax(word_size) >> 1) as i128
+}
+
+/// Gets minimum of signed integer of `word_size <= 16` bytes.
+const fn imin(word_size: usize) -> i128 {
+    -imax(word_size) - 1
+}
+
+/// Wraps `value` into signed integer of `word_size <= 16` bytes.
+const fn iwrap(value: i128, word_size: usize) -> i128 {
+    let shift = (U128 - word_size) * 8;
+    (value << shift) >> shift
+}
+
+/// Reverses the order of the 16-bit words of `value`.
+const fn swap_words_u64(value: u64) -> u64 {
+    let value = value.swap_bytes();
+    ((value & 0x00ff_00ff_00ff_00ff) << 8) | ((value >> 8) & 0x00ff_00ff_00ff_00ff)
+}
+
+/// Reverses the order of the 16-bit words of `value` of `word_size <= 16` bytes.
+///
+/// Panics if the word size is odd and above two, see [`Error::WordSwap`].
+const fn swap_words_uint128(value: u128, word_size: usize) -> u128 {
+    if let Err(Error::WordSwap { .. }) = word_swap(word_size, true) {
+        panic!("word of odd size cannot be word-swapped");
+    }
+    if word_size <= U16 {
+        value
+    } else {
+        swap_words_u128(value) >> ((U128 - word_size) * 8)
+    }
+}
+
+/// Reverses the order of the 16-bit words of `value`.
+const fn swap_words_u128(value: u128) -> u128 {
+    let value = value.swap_bytes();
+    ((value & 0x00ff_00ff_00ff_00ff_00ff_00ff_00ff_00ff) << 8)
+        | ((value >> 8) & 0x00ff_00ff_00ff_00ff_00ff_00ff_00ff_00ff)
+}
+
 /// Provides endian-independent immutable bytes access.
 ///
 /// Requires methods to be implemented detecting or hardcoding the word size and endianness. This
 /// trait requires the <code>[AsRef]<\[[u8]\]></code> trait to access slices of generic types. It is
 /// not implemented for the [`Raw`] trait but instead for its wrapper types since each wrapper might
-/// implement the endianness detection differently. The generic type parameter `Scope` allows to
-/// define the trait's visibility, e.g., by assigning a private type instead of the public default
-/// type parameter `Scope = ()`.
+/// implement the endianness detection differently. See [`Packet`], [`Be`], [`Le`], and [`Dyn`] for
+/// ready-made wrapper types. The generic type parameter `Scope` allows to define the trait's
+/// visibility, e.g., by assigning a private type instead of the public default type parameter
+/// `Scope = ()`.
 pub trait InSitu<Scope = ()>: AsRef<[u8]> {
     /// The word size of the slice required by [`Self::at()`], not to be confused with the various
     /// word sizes of how to access the slice. Use `0` if [`Self::is_le()`] does not affect the
@@ -86,39 +213,265 @@ pub trait InSitu<Scope = ()>: AsRef<[u8]> {
     fn is_le(&self) -> bool {
         !self.is_be()
     }
+    /// Whether the 16-bit words of 32-bit and wider words are in reverse order, i.e., in
+    /// little-endian word order if [`Self::is_be()`] and in big-endian word order if
+    /// [`Self::is_le()`]. Defaults to `false`.
+    ///
+    /// This is the mixed-endian byte order of [`Order::CDAB`] and [`Order::BADC`] affecting the
+    /// accessors of [`u32`], [`u64`], [`u128`], [`i32`], [`i64`], [`i128`], [`f32`], and [`f64`]
+    /// as well as of variable-width integers of even word sizes. Words of odd sizes above two bytes
+    /// like `u24` cannot be word-swapped, their accessors panic and their `try_` variants return
+    /// [`Error::WordSwap`].
+    fn is_word_swapped(&self) -> bool {
+        false
+    }
     /// Tests if the underlying byte order has the machine's native endianness.
     fn is_native(&self) -> bool {
-        self.is_be() == (NativeEndian::read_u16(&[0, 1]) == 1)
+        self.is_be() == (NativeEndian::read_u16(&[0, 1]) == 1) && !self.is_word_swapped()
     }
-    /// Convert [`Self::is_be()`] and [`Self::is_le()`] into `Order`.
+    /// Convert [`Self::is_be()`], [`Self::is_le()`], and [`Self::is_word_swapped()`] into `Order`.
     fn order(&self) -> Order {
-        if self.is_be() { Order::BE } else { Order::LE }
+        match (self.is_be(), self.is_word_swapped()) {
+            (true, false) => Order::BE,
+            (false, false) => Order::LE,
+            (true, true) => Order::CDAB,
+            (false, true) => Order::BADC,
+        }
     }
     /// If [`Self::is_le()`], translates big-endian `offset` of word with `word_size` in slice of
-    /// [`Self::swap_size()`] into little-endian via bitwise instead of branching and modulo
-    /// operations, otherwise passes through `offset`.
+    /// [`Self::swap_size()`] into little-endian via bitwise instead of modulo operations, otherwise
+    /// passes through `offset`.
+    ///
+    /// Words of any `word_size` narrower than the slice are mirrored within the slice, whereas
+    /// words at least as wide as the slice are passed through at any offset like in big-endian
+    /// views. The swap size must be zero or a power of two and a narrower word must not cross a
+    /// slice boundary of a little-endian view, which is debug-asserted and checked by
+    /// [`Self::try_at()`].
     fn at(&self, offset: usize, word_size: usize) -> usize {
-        if self.is_be() || self.swap_size() < word_size {
+        let swap_size = self.swap_size();
+        debug_assert!(swap(swap_size).is_ok(), "invalid swap size {swap_size}");
+        debug_assert!(
+            self.is_be() || crossing(offset, word_size, swap_size).is_ok(),
+            "word of {word_size} bytes at offset {offset} crosses slice of {swap_size} bytes"
+        );
+        if self.is_be() || swap_size <= word_size {
             offset
         } else {
-            offset ^ (self.swap_size() - word_size)
+            let start = offset & (swap_size - 1);
+            offset - start + (swap_size - word_size - start)
+        }
+    }
+    /// Tries to translate `offset` like [`Self::at()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SwapSize`] if the swap size is neither zero nor a power of two and
+    /// [`Error::Crossing`] if the word of a little-endian view is narrower than the slice and
+    /// crosses its boundary.
+    fn try_at(&self, offset: usize, word_size: usize) -> Result<usize, Error> {
+        let swap_size = self.swap_size();
+        swap(swap_size)?;
+        if self.is_le() {
+            crossing(offset, word_size, swap_size)?;
         }
+        Ok(self.at(offset, word_size))
     }
     /// Gets [`&str`] if UTF-8 in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
     ///
+    /// The bytes are taken as they are, see `Self::utf8_unswapped()` for strings whose bytes are
+    /// swapped in slices of [`Self::swap_size()`].
+    ///
     /// # Errors
     ///
     /// Returns [`Utf8Error`] if the slice is not UTF-8 with a description as to why the provided
     /// slice is not UTF-8.
     fn utf8(&self, offset: usize, length: usize) -> Result<&str, Utf8Error> {
-        std::str::from_utf8(&self.as_ref()[offset..][..length])
+        core::str::from_utf8(&self.as_ref()[offset..][..length])
     }
     /// Gets [`BStr`] in slice of [`Self::swap_size()`] at big-endian `offset` endian-independently.
+    ///
+    /// The bytes are taken as they are, see `Self::bstr_unswapped()` for strings whose bytes are
+    /// swapped in slices of [`Self::swap_size()`].
     #[cfg(feature = "bstr")]
     fn bstr(&self, offset: usize, length: usize) -> &BStr {
         BStr::new(&self.as_ref()[offset..][..length])
     }
+    /// Gets [`&str`] if UTF-8 at big-endian `offset` with its bytes un-swapped via [`Self::at()`].
+    ///
+    /// Strings like ATA model numbers are stored byte-swapped in slices of [`Self::swap_size()`]
+    /// if [`Self::is_le()`], i.e., each byte is read like [`Self::u8()`]. Borrows the bytes if
+    /// they are not swapped, otherwise copies them.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if a byte exceeds the slice and [`Error::Utf8`] if the
+    /// un-swapped bytes are not UTF-8.
+    #[cfg(feature = "alloc")]
+    fn utf8_unswapped(&self, offset: usize, length: usize) -> Result<Cow<'_, str>, Error> {
+        if self.is_be() || self.swap_size() <= U8 {
+            return self.try_utf8(offset, length).map(Cow::Borrowed);
+        }
+        let bytes = self.unswapped(offset, length)?;
+        let string = String::from_utf8(bytes).map_err(|error| error.utf8_error())?;
+        Ok(Cow::Owned(string))
+    }
+    /// Gets [`BStr`] at big-endian `offset` with its bytes un-swapped via [`Self::at()`].
+    ///
+    /// See [`Self::utf8_unswapped()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if a byte exceeds the slice.
+    #[cfg(all(feature = "alloc", feature = "bstr"))]
+    fn bstr_unswapped(&self, offset: usize, length: usize) -> Result<Cow<'_, BStr>, Error> {
+        if self.is_be() || self.swap_size() <= U8 {
+            return self.try_bstr(offset, length).map(Cow::Borrowed);
+        }
+        let bytes = self.unswapped(offset, length)?;
+        Ok(Cow::Owned(BString::from(bytes)))
+    }
+    /// Gets NUL-terminated [`&str`] if UTF-8 of at most `max` bytes at big-endian `offset`.
+    ///
+    /// Takes all `max` bytes if there is no NUL terminator. The bytes are taken as they are like
+    /// with [`Self::utf8()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `offset` or the unterminated string exceeds the slice
+    /// and [`Error::Utf8`] if the string is not UTF-8.
+    fn cstr(&self, offset: usize, max: usize) -> Result<&str, Error> {
+        let length = terminated(self.as_ref(), offset, max)?;
+        Ok(self.utf8(offset, length)?)
+    }
+    /// Gets [`&str`] if UTF-8 prefixed with its length as unsigned integer of `prefix_size <= 8` at
+    /// big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `prefix_size` is zero or exceeds `8`, [`Error::Overflow`] if
+    /// the length exceeds [`usize`], [`Error::OutOfBounds`] if the prefix or the string exceeds the
+    /// slice, and [`Error::Utf8`] if the string is not UTF-8.
+    fn pstr(&self, offset: usize, prefix_size: usize) -> Result<&str, Error> {
+        let length =
+            usize::try_from(self.try_uint(offset, prefix_size)?).map_err(|_| Error::Overflow {
+                word_size: prefix_size,
+            })?;
+        self.try_utf8(offset + prefix_size, length)
+    }
+    /// Gets [`&str`] if UTF-8 of `width` bytes at big-endian `offset` without its trailing `pad`
+    /// bytes, e.g., `b'\0'` or `b' '`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the `width` bytes exceed the slice and [`Error::Utf8`] if
+    /// the string is not UTF-8.
+    fn padded_utf8(&self, offset: usize, width: usize, pad: u8) -> Result<&str, Error> {
+        let length = trimmed(self.as_ref(), offset, width, pad)?;
+        Ok(self.utf8(offset, length)?)
+    }
+    /// Gets NUL-terminated [`BStr`] of at most `max` bytes at big-endian `offset`.
+    ///
+    /// Takes all `max` bytes if there is no NUL terminator. The bytes are taken as they are like
+    /// with [`Self::bstr()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `offset` or the unterminated string exceeds the slice.
+    #[cfg(feature = "bstr")]
+    fn cbstr(&self, offset: usize, max: usize) -> Result<&BStr, Error> {
+        let length = terminated(self.as_ref(), offset, max)?;
+        Ok(self.bstr(offset, length))
+    }
+    /// Gets [`BStr`] prefixed with its length as unsigned integer of `prefix_size <= 8` at
+    /// big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `prefix_size` is zero or exceeds `8`, [`Error::Overflow`] if
+    /// the length exceeds [`usize`], [`Error::OutOfBounds`] if the prefix or the string exceeds the
+    /// slice.
+    #[cfg(feature = "bstr")]
+    fn pbstr(&self, offset: usize, prefix_size: usize) -> Result<&BStr, Error> {
+        let length =
+            usize::try_from(self.try_uint(offset, prefix_size)?).map_err(|_| Error::Overflow {
+                word_size: prefix_size,
+            })?;
+        self.try_bstr(offset + prefix_size, length)
+    }
+    /// Gets [`BStr`] of `width` bytes at big-endian `offset` without its trailing `pad` bytes,
+    /// e.g., `b'\0'` or `b' '`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the `width` bytes exceed the slice.
+    #[cfg(feature = "bstr")]
+    fn padded_bstr(&self, offset: usize, width: usize, pad: u8) -> Result<&BStr, Error> {
+        let length = trimmed(self.as_ref(), offset, width, pad)?;
+        Ok(self.bstr(offset, length))
+    }
+    /// Copies `length` bytes at big-endian `offset` with the bytes un-swapped via [`Self::at()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if a byte exceeds the slice.
+    #[cfg(feature = "alloc")]
+    fn unswapped(&self, offset: usize, length: usize) -> Result<Vec<u8>, Error> {
+        bounds(self.as_ref(), offset, length)?;
+        (offset..offset + length)
+            .map(|offset| self.try_u8(offset))
+            .collect()
+    }
+    /// Iterates [`char`]s of UTF-16 string of `code_units` at big-endian `offset`
+    /// endian-independently without allocating.
+    ///
+    /// Decodes UCS-2 as well, being a subset of UTF-16. Yields [`DecodeUtf16Error`] for unpaired
+    /// surrogates.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if a code unit exceeds the slice.
+    ///
+    /// [`DecodeUtf16Error`]: core::char::DecodeUtf16Error
+    fn utf16_chars(
+        &self,
+        offset: usize,
+        code_units: usize,
+    ) -> Result<DecodeUtf16<InSituIter<'_, Self, u16>>, Error>
+    where
+        Self: Sized,
+    {
+        for offset in (offset..).step_by(U16).take(code_units) {
+            bounds(self.as_ref(), self.try_at(offset, U16)?, U16)?;
+        }
+        Ok(char::decode_utf16(self.iter_u16(offset, code_units)))
+    }
+    /// Gets [`String`] of UTF-16 string of `code_units` at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// Decodes UCS-2 as well, being a subset of UTF-16.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if a code unit exceeds the slice and [`Error::Utf16`] if
+    /// there is an unpaired surrogate.
+    #[cfg(feature = "alloc")]
+    fn utf16(&self, offset: usize, code_units: usize) -> Result<String, Error>
+    where
+        Self: Sized,
+    {
+        let mut string = String::with_capacity(code_units);
+        let mut at = offset;
+        for character in self.utf16_chars(offset, code_units)? {
+            let character = character.map_err(|error| Error::Utf16 {
+                offset: at,
+                surrogate: error.unpaired_surrogate(),
+            })?;
+            string.push(character);
+            at += character.len_utf16() * U16;
+        }
+        Ok(string)
+    }
     /// Gets [`bool`] in slice of [`Self::swap_size()`] at big-endian `offset` endian-independently.
     fn bool(&self, offset: usize) -> bool {
         self.u8(offset) != 0
@@ -139,7 +492,16 @@ pub trait InSitu<Scope = ()>: AsRef<[u8]> {
     }
     /// Gets `u24` as [`u32`] in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Self::is_word_swapped()`] since the word is of odd size, see
+    /// [`Self::try_u24()`].
+    #[allow(clippy::cast_possible_truncation)]
     fn u24(&self, offset: usize) -> u32 {
+        if self.is_word_swapped() {
+            return self.uint128(offset, U24) as u32;
+        }
         let offset = self.at(offset, U24);
         if self.is_be() {
             BE::read_u24(&self.as_ref()[offset..])
@@ -150,33 +512,57 @@ pub trait InSitu<Scope = ()>: AsRef<[u8]> {
     /// Gets [`u32`] in slice of [`Self::swap_size()`] at big-endian `offset` endian-independently.
     fn u32(&self, offset: usize) -> u32 {
         let offset = self.at(offset, U32);
-        if self.is_be() {
+        let value = if self.is_be() {
             BE::read_u32(&self.as_ref()[offset..])
         } else {
             LE::read_u32(&self.as_ref()[offset..])
+        };
+        if self.is_word_swapped() {
+            value.rotate_left(16)
+        } else {
+            value
         }
     }
     /// Gets [`u64`] in slice of [`Self::swap_size()`] at big-endian `offset` endian-independently.
     fn u64(&self, offset: usize) -> u64 {
         let offset = self.at(offset, U64);
-        if self.is_be() {
+        let value = if self.is_be() {
             BE::read_u64(&self.as_ref()[offset..])
         } else {
             LE::read_u64(&self.as_ref()[offset..])
+        };
+        if self.is_word_swapped() {
+            swap_words_u64(value)
+        } else {
+            value
         }
     }
     /// Gets [`u128`] in slice of [`Self::swap_size()`] at big-endian `offset` endian-independently.
     fn u128(&self, offset: usize) -> u128 {
         let offset = self.at(offset, U128);
-        if self.is_be() {
+        let value = if self.is_be() {
             BE::read_u128(&self.as_ref()[offset..])
         } else {
             LE::read_u128(&self.as_ref()[offset..])
+        };
+        if self.is_word_swapped() {
+            swap_words_u128(value)
+        } else {
+            value
         }
     }
     /// Gets unsigned integer of `word_size <= 8` in slice of [`Self::swap_size()`] at big-endian
     /// `offset` endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Self::is_word_swapped()`] and `word_size` is odd and above two bytes, see
+    /// [`Self::try_uint()`].
+    #[allow(clippy::cast_possible_truncation)]
     fn uint(&self, offset: usize, word_size: usize) -> u64 {
+        if self.is_word_swapped() {
+            return self.uint128(offset, word_size) as u64;
+        }
         let offset = self.at(offset, word_size);
         if self.is_be() {
             BE::read_uint(&self.as_ref()[offset..], word_size)
@@ -186,12 +572,22 @@ pub trait InSitu<Scope = ()>: AsRef<[u8]> {
     }
     /// Gets unsigned integer of `word_size <= 16` in slice of [`Self::swap_size()`] at big-endian
     /// `offset` endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Self::is_word_swapped()`] and `word_size` is odd and above two bytes, see
+    /// [`Self::try_uint128()`].
     fn uint128(&self, offset: usize, word_size: usize) -> u128 {
         let offset = self.at(offset, word_size);
-        if self.is_be() {
+        let value = if self.is_be() {
             BE::read_uint128(&self.as_ref()[offset..], word_size)
         } else {
             LE::read_uint128(&self.as_ref()[offset..], word_size)
+        };
+        if self.is_word_swapped() {
+            swap_words_uint128(value, word_size)
+        } else {
+            value
         }
     }
     /// Gets [`i8`] in slice of [`Self::swap_size()`] at big-endian `offset` endian-independently.
@@ -211,7 +607,16 @@ pub trait InSitu<Scope = ()>: AsRef<[u8]> {
     }
     /// Gets `i24` as [`i32`] in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Self::is_word_swapped()`] since the word is of odd size, see
+    /// [`Self::try_i24()`].
+    #[allow(clippy::cast_possible_truncation)]
     fn i24(&self, offset: usize) -> i32 {
+        if self.is_word_swapped() {
+            return self.int128(offset, I24) as i32;
+        }
         let offset = self.at(offset, I24);
         if self.is_be() {
             BE::read_i24(&self.as_ref()[offset..])
@@ -220,7 +625,11 @@ pub trait InSitu<Scope = ()>: AsRef<[u8]> {
         }
     }
     /// Gets [`i32`] in slice of [`Self::swap_size()`] at big-endian `offset` endian-independently.
+    #[allow(clippy::cast_possible_wrap)]
     fn i32(&self, offset: usize) -> i32 {
+        if self.is_word_swapped() {
+            return self.u32(offset) as i32;
+        }
         let offset = self.at(offset, I32);
         if self.is_be() {
             BE::read_i32(&self.as_ref()[offset..])
@@ -229,7 +638,11 @@ pub trait InSitu<Scope = ()>: AsRef<[u8]> {
         }
     }
     /// Gets [`i64`] in slice of [`Self::swap_size()`] at big-endian `offset` endian-independently.
+    #[allow(clippy::cast_possible_wrap)]
     fn i64(&self, offset: usize) -> i64 {
+        if self.is_word_swapped() {
+            return self.u64(offset) as i64;
+        }
         let offset = self.at(offset, I64);
         if self.is_be() {
             BE::read_i64(&self.as_ref()[offset..])
@@ -238,7 +651,11 @@ pub trait InSitu<Scope = ()>: AsRef<[u8]> {
         }
     }
     /// Gets [`u128`] in slice of [`Self::swap_size()`] at big-endian `offset` endian-independently.
+    #[allow(clippy::cast_possible_wrap)]
     fn i128(&self, offset: usize) -> i128 {
+        if self.is_word_swapped() {
+            return self.u128(offset) as i128;
+        }
         let offset = self.at(offset, I128);
         if self.is_be() {
             BE::read_i128(&self.as_ref()[offset..])
@@ -248,7 +665,16 @@ pub trait InSitu<Scope = ()>: AsRef<[u8]> {
     }
     /// Gets signed integer of `word_size <= 8` in slice of [`Self::swap_size()`] at big-endian
     /// `offset` endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Self::is_word_swapped()`] and `word_size` is odd and above two bytes, see
+    /// [`Self::try_int()`].
+    #[allow(clippy::cast_possible_truncation)]
     fn int(&self, offset: usize, word_size: usize) -> i64 {
+        if self.is_word_swapped() {
+            return self.int128(offset, word_size) as i64;
+        }
         let offset = self.at(offset, word_size);
         if self.is_be() {
             BE::read_int(&self.as_ref()[offset..], word_size)
@@ -258,7 +684,16 @@ pub trait InSitu<Scope = ()>: AsRef<[u8]> {
     }
     /// Gets signed integer of `word_size <= 16` in slice of [`Self::swap_size()`] at big-endian
     /// `offset` endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`Self::is_word_swapped()`] and `word_size` is odd and above two bytes, see
+    /// [`Self::try_int128()`].
+    #[allow(clippy::cast_possible_wrap)]
     fn int128(&self, offset: usize, word_size: usize) -> i128 {
+        if self.is_word_swapped() {
+            return iwrap(self.uint128(offset, word_size) as i128, word_size);
+        }
         let offset = self.at(offset, word_size);
         if self.is_be() {
             BE::read_int128(&self.as_ref()[offset..], word_size)
@@ -268,6 +703,9 @@ pub trait InSitu<Scope = ()>: AsRef<[u8]> {
     }
     /// Gets [`f32`] in slice of [`Self::swap_size()`] at big-endian `offset` endian-independently.
     fn f32(&self, offset: usize) -> f32 {
+        if self.is_word_swapped() {
+            return f32::from_bits(self.u32(offset));
+        }
         let offset = self.at(offset, F32);
         if self.is_be() {
             BE::read_f32(&self.as_ref()[offset..])
@@ -277,6 +715,9 @@ pub trait InSitu<Scope = ()>: AsRef<[u8]> {
     }
     /// Gets [`f64`] in slice of [`Self::swap_size()`] at big-endian `offset` endian-independently.
     fn f64(&self, offset: usize) -> f64 {
+        if self.is_word_swapped() {
+            return f64::from_bits(self.u64(offset));
+        }
         let offset = self.at(offset, F64);
         if self.is_be() {
             BE::read_f64(&self.as_ref()[offset..])
@@ -284,193 +725,2553 @@ pub trait InSitu<Scope = ()>: AsRef<[u8]> {
             LE::read_f64(&self.as_ref()[offset..])
         }
     }
-}
-
-/// Provides endian-independent mutable bytes access.
-///
-/// Requires <code>[InSitu]\<Scope\></code> trait to know about endianness.
-/// <code>[InSituMut]\<Scope\></code> is **not (yet)** auto-implemented for all
-/// <code>[InSitu]\<Scope\> + [AsMut]\<\[[u8]\]\></code> implementors as the trait name would leak
-/// into the documentation under blanket implementations even for a private generic type parameter
-/// `Scope`. This might be resolved in the far future by a language extension supporting scoped
-/// trait implementations or by fixing *rustdoc* if there are no loopholes which would allow to
-/// actually use the trait.
-pub trait InSituMut<Scope = ()>: InSitu<Scope> + AsMut<[u8]> {
-    /// Sets [`bool`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// Gets [`u16`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
-    fn set_bool(&mut self, offset: usize, value: bool) {
-        self.set_u8(offset, value.into());
-    }
-    /// Sets [`u8`] in slice of [`InSitu::swap_size()`] at big-endian `offset` endian-independently.
-    fn set_u8(&mut self, offset: usize, value: u8) {
-        let at = self.at(offset, U8);
-        self.as_mut()[at] = value;
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`Self::is_native()`]. Falls
+    /// back to getting word by word if [`Self::at()`] translates the offsets or if the words are
+    /// swapped.
+    fn read_u16_into(&self, offset: usize, dst: &mut [u16]) {
+        if self.is_le() && self.swap_size() > U16 {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.u16(offset + index * U16);
+            }
+        } else {
+            let src = &self.as_ref()[offset..][..dst.len() * U16];
+            if self.is_be() {
+                BE::read_u16_into(src, dst);
+            } else {
+                LE::read_u16_into(src, dst);
+            }
+        }
     }
-    /// Sets [`u16`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// Gets [`u32`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
-    fn set_u16(&mut self, offset: usize, value: u16) {
-        let offset = self.at(offset, U16);
-        if self.is_be() {
-            BE::write_u16(&mut self.as_mut()[offset..], value);
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`Self::is_native()`]. Falls
+    /// back to getting word by word if [`Self::at()`] translates the offsets or if the words are
+    /// swapped.
+    fn read_u32_into(&self, offset: usize, dst: &mut [u32]) {
+        if (self.is_le() && self.swap_size() > U32) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.u32(offset + index * U32);
+            }
         } else {
-            LE::write_u16(&mut self.as_mut()[offset..], value);
+            let src = &self.as_ref()[offset..][..dst.len() * U32];
+            if self.is_be() {
+                BE::read_u32_into(src, dst);
+            } else {
+                LE::read_u32_into(src, dst);
+            }
         }
     }
-    /// Sets `u24` as [`u32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// Gets [`u64`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
-    fn set_u24(&mut self, offset: usize, value: u32) {
-        let offset = self.at(offset, U24);
-        if self.is_be() {
-            BE::write_u24(&mut self.as_mut()[offset..], value);
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`Self::is_native()`]. Falls
+    /// back to getting word by word if [`Self::at()`] translates the offsets or if the words are
+    /// swapped.
+    fn read_u64_into(&self, offset: usize, dst: &mut [u64]) {
+        if (self.is_le() && self.swap_size() > U64) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.u64(offset + index * U64);
+            }
         } else {
-            LE::write_u24(&mut self.as_mut()[offset..], value);
+            let src = &self.as_ref()[offset..][..dst.len() * U64];
+            if self.is_be() {
+                BE::read_u64_into(src, dst);
+            } else {
+                LE::read_u64_into(src, dst);
+            }
         }
     }
-    /// Sets [`u32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// Gets [`u128`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
-    fn set_u32(&mut self, offset: usize, value: u32) {
-        let offset = self.at(offset, U32);
-        if self.is_be() {
-            BE::write_u32(&mut self.as_mut()[offset..], value);
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`Self::is_native()`]. Falls
+    /// back to getting word by word if [`Self::at()`] translates the offsets or if the words are
+    /// swapped.
+    fn read_u128_into(&self, offset: usize, dst: &mut [u128]) {
+        if (self.is_le() && self.swap_size() > U128) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.u128(offset + index * U128);
+            }
         } else {
-            LE::write_u32(&mut self.as_mut()[offset..], value);
+            let src = &self.as_ref()[offset..][..dst.len() * U128];
+            if self.is_be() {
+                BE::read_u128_into(src, dst);
+            } else {
+                LE::read_u128_into(src, dst);
+            }
         }
     }
-    /// Sets [`u64`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// Gets [`i16`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
-    fn set_u64(&mut self, offset: usize, value: u64) {
-        let offset = self.at(offset, U64);
-        if self.is_be() {
-            BE::write_u64(&mut self.as_mut()[offset..], value);
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`Self::is_native()`]. Falls
+    /// back to getting word by word if [`Self::at()`] translates the offsets or if the words are
+    /// swapped.
+    fn read_i16_into(&self, offset: usize, dst: &mut [i16]) {
+        if self.is_le() && self.swap_size() > I16 {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.i16(offset + index * I16);
+            }
         } else {
-            LE::write_u64(&mut self.as_mut()[offset..], value);
+            let src = &self.as_ref()[offset..][..dst.len() * I16];
+            if self.is_be() {
+                BE::read_i16_into(src, dst);
+            } else {
+                LE::read_i16_into(src, dst);
+            }
         }
     }
-    /// Sets [`u128`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// Gets [`i32`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
-    fn set_u128(&mut self, offset: usize, value: u128) {
-        let offset = self.at(offset, U128);
-        if self.is_be() {
-            BE::write_u128(&mut self.as_mut()[offset..], value);
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`Self::is_native()`]. Falls
+    /// back to getting word by word if [`Self::at()`] translates the offsets or if the words are
+    /// swapped.
+    fn read_i32_into(&self, offset: usize, dst: &mut [i32]) {
+        if (self.is_le() && self.swap_size() > I32) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.i32(offset + index * I32);
+            }
         } else {
-            LE::write_u128(&mut self.as_mut()[offset..], value);
+            let src = &self.as_ref()[offset..][..dst.len() * I32];
+            if self.is_be() {
+                BE::read_i32_into(src, dst);
+            } else {
+                LE::read_i32_into(src, dst);
+            }
         }
     }
-    /// Sets unsigned integer of `word_size <= 8` in slice of [`InSitu::swap_size()`] at big-endian
-    /// `offset` endian-independently.
-    fn set_uint(&mut self, offset: usize, value: u64, word_size: usize) {
-        let offset = self.at(offset, word_size);
-        if self.is_be() {
-            BE::write_uint(&mut self.as_mut()[offset..], value, word_size);
+    /// Gets [`i64`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`Self::is_native()`]. Falls
+    /// back to getting word by word if [`Self::at()`] translates the offsets or if the words are
+    /// swapped.
+    fn read_i64_into(&self, offset: usize, dst: &mut [i64]) {
+        if (self.is_le() && self.swap_size() > I64) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.i64(offset + index * I64);
+            }
         } else {
-            LE::write_uint(&mut self.as_mut()[offset..], value, word_size);
+            let src = &self.as_ref()[offset..][..dst.len() * I64];
+            if self.is_be() {
+                BE::read_i64_into(src, dst);
+            } else {
+                LE::read_i64_into(src, dst);
+            }
         }
     }
-    /// Sets unsigned integer of `word_size <= 16` in slice of [`InSitu::swap_size()`] at big-endian
-    /// `offset` endian-independently.
-    fn set_uint128(&mut self, offset: usize, value: u128, word_size: usize) {
-        let offset = self.at(offset, word_size);
-        if self.is_be() {
-            BE::write_uint128(&mut self.as_mut()[offset..], value, word_size);
+    /// Gets [`i128`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`Self::is_native()`]. Falls
+    /// back to getting word by word if [`Self::at()`] translates the offsets or if the words are
+    /// swapped.
+    fn read_i128_into(&self, offset: usize, dst: &mut [i128]) {
+        if (self.is_le() && self.swap_size() > I128) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.i128(offset + index * I128);
+            }
         } else {
-            LE::write_uint128(&mut self.as_mut()[offset..], value, word_size);
+            let src = &self.as_ref()[offset..][..dst.len() * I128];
+            if self.is_be() {
+                BE::read_i128_into(src, dst);
+            } else {
+                LE::read_i128_into(src, dst);
+            }
         }
     }
-    /// Sets [`i8`] in slice of [`InSitu::swap_size()`] at big-endian `offset` endian-independently.
-    #[allow(clippy::cast_sign_loss)]
-    fn set_i8(&mut self, offset: usize, value: i8) {
-        let at = self.at(offset, I8);
-        self.as_mut()[at] = value as u8;
-    }
-    /// Sets [`i16`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// Gets [`f32`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
-    fn set_i16(&mut self, offset: usize, value: i16) {
-        let offset = self.at(offset, I16);
-        if self.is_be() {
-            BE::write_i16(&mut self.as_mut()[offset..], value);
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`Self::is_native()`]. Falls
+    /// back to getting word by word if [`Self::at()`] translates the offsets or if the words are
+    /// swapped.
+    fn read_f32_into(&self, offset: usize, dst: &mut [f32]) {
+        if (self.is_le() && self.swap_size() > F32) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.f32(offset + index * F32);
+            }
         } else {
-            LE::write_i16(&mut self.as_mut()[offset..], value);
+            let src = &self.as_ref()[offset..][..dst.len() * F32];
+            if self.is_be() {
+                BE::read_f32_into(src, dst);
+            } else {
+                LE::read_f32_into(src, dst);
+            }
         }
     }
-    /// Sets `i24` as [`i32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// Gets [`f64`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
-    fn set_i24(&mut self, offset: usize, value: i32) {
-        let offset = self.at(offset, I24);
-        if self.is_be() {
-            BE::write_i24(&mut self.as_mut()[offset..], value);
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`Self::is_native()`]. Falls
+    /// back to getting word by word if [`Self::at()`] translates the offsets or if the words are
+    /// swapped.
+    fn read_f64_into(&self, offset: usize, dst: &mut [f64]) {
+        if (self.is_le() && self.swap_size() > F64) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.f64(offset + index * F64);
+            }
         } else {
-            LE::write_i24(&mut self.as_mut()[offset..], value);
+            let src = &self.as_ref()[offset..][..dst.len() * F64];
+            if self.is_be() {
+                BE::read_f64_into(src, dst);
+            } else {
+                LE::read_f64_into(src, dst);
+            }
         }
     }
-    /// Sets [`i32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// Tries to get [`u16`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
-    fn set_i32(&mut self, offset: usize, value: i32) {
-        let offset = self.at(offset, I32);
-        if self.is_be() {
-            BE::write_i32(&mut self.as_mut()[offset..], value);
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case `dst` might be
+    /// partially overwritten.
+    fn try_read_u16_into(&self, offset: usize, dst: &mut [u16]) -> Result<(), Error> {
+        if self.is_le() && self.swap_size() > U16 {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.try_u16(offset + index * U16)?;
+            }
         } else {
-            LE::write_i32(&mut self.as_mut()[offset..], value);
+            bounds(self.as_ref(), offset, dst.len() * U16)?;
+            self.read_u16_into(offset, dst);
         }
+        Ok(())
     }
-    /// Sets [`i64]` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// Tries to get [`u32`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
-    fn set_i64(&mut self, offset: usize, value: i64) {
-        let offset = self.at(offset, I64);
-        if self.is_be() {
-            BE::write_i64(&mut self.as_mut()[offset..], value);
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case `dst` might be
+    /// partially overwritten.
+    fn try_read_u32_into(&self, offset: usize, dst: &mut [u32]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > U32) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.try_u32(offset + index * U32)?;
+            }
         } else {
-            LE::write_i64(&mut self.as_mut()[offset..], value);
+            bounds(self.as_ref(), offset, dst.len() * U32)?;
+            self.read_u32_into(offset, dst);
         }
+        Ok(())
     }
-    /// Sets [`i128`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// Tries to get [`u64`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
     /// endian-independently.
-    fn set_i128(&mut self, offset: usize, value: i128) {
-        let offset = self.at(offset, I128);
-        if self.is_be() {
-            BE::write_i128(&mut self.as_mut()[offset..], value);
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case `dst` might be
+    /// partially overwritten.
+    fn try_read_u64_into(&self, offset: usize, dst: &mut [u64]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > U64) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.try_u64(offset + index * U64)?;
+            }
         } else {
-            LE::write_i128(&mut self.as_mut()[offset..], value);
+            bounds(self.as_ref(), offset, dst.len() * U64)?;
+            self.read_u64_into(offset, dst);
         }
+        Ok(())
     }
-    /// Sets signed integer of `word_size <= 8` in slice of [`InSitu::swap_size()`] at big-endian
-    /// `offset` endian-independently.
-    fn set_int(&mut self, offset: usize, value: i64, word_size: usize) {
-        let offset = self.at(offset, word_size);
+    /// Tries to get [`u128`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case `dst` might be
+    /// partially overwritten.
+    fn try_read_u128_into(&self, offset: usize, dst: &mut [u128]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > U128) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.try_u128(offset + index * U128)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, dst.len() * U128)?;
+            self.read_u128_into(offset, dst);
+        }
+        Ok(())
+    }
+    /// Tries to get [`i16`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case `dst` might be
+    /// partially overwritten.
+    fn try_read_i16_into(&self, offset: usize, dst: &mut [i16]) -> Result<(), Error> {
+        if self.is_le() && self.swap_size() > I16 {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.try_i16(offset + index * I16)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, dst.len() * I16)?;
+            self.read_i16_into(offset, dst);
+        }
+        Ok(())
+    }
+    /// Tries to get [`i32`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case `dst` might be
+    /// partially overwritten.
+    fn try_read_i32_into(&self, offset: usize, dst: &mut [i32]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > I32) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.try_i32(offset + index * I32)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, dst.len() * I32)?;
+            self.read_i32_into(offset, dst);
+        }
+        Ok(())
+    }
+    /// Tries to get [`i64`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case `dst` might be
+    /// partially overwritten.
+    fn try_read_i64_into(&self, offset: usize, dst: &mut [i64]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > I64) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.try_i64(offset + index * I64)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, dst.len() * I64)?;
+            self.read_i64_into(offset, dst);
+        }
+        Ok(())
+    }
+    /// Tries to get [`i128`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case `dst` might be
+    /// partially overwritten.
+    fn try_read_i128_into(&self, offset: usize, dst: &mut [i128]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > I128) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.try_i128(offset + index * I128)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, dst.len() * I128)?;
+            self.read_i128_into(offset, dst);
+        }
+        Ok(())
+    }
+    /// Tries to get [`f32`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case `dst` might be
+    /// partially overwritten.
+    fn try_read_f32_into(&self, offset: usize, dst: &mut [f32]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > F32) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.try_f32(offset + index * F32)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, dst.len() * F32)?;
+            self.read_f32_into(offset, dst);
+        }
+        Ok(())
+    }
+    /// Tries to get [`f64`]s into `dst` in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case `dst` might be
+    /// partially overwritten.
+    fn try_read_f64_into(&self, offset: usize, dst: &mut [f64]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > F64) || self.is_word_swapped() {
+            for (index, value) in dst.iter_mut().enumerate() {
+                *value = self.try_f64(offset + index * F64)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, dst.len() * F64)?;
+            self.read_f64_into(offset, dst);
+        }
+        Ok(())
+    }
+
+    /// Iterates `count` contiguous [`u8`]s in slice of [`Self::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    fn iter_u8(&self, offset: usize, count: usize) -> InSituIter<'_, Self, u8>
+    where
+        Self: Sized,
+    {
+        self.iter_u8_strided(offset, U8, count)
+    }
+    /// Iterates `count` [`u8`]s `stride` bytes apart in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_u8_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, u8>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::u8, offset, stride, count)
+    }
+    /// Iterates `count` contiguous [`u16`]s in slice of [`Self::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    fn iter_u16(&self, offset: usize, count: usize) -> InSituIter<'_, Self, u16>
+    where
+        Self: Sized,
+    {
+        self.iter_u16_strided(offset, U16, count)
+    }
+    /// Iterates `count` [`u16`]s `stride` bytes apart in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_u16_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, u16>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::u16, offset, stride, count)
+    }
+    /// Iterates `count` contiguous `u24`s as [`u32`]s in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_u24(&self, offset: usize, count: usize) -> InSituIter<'_, Self, u32>
+    where
+        Self: Sized,
+    {
+        self.iter_u24_strided(offset, U24, count)
+    }
+    /// Iterates `count` `u24`s as [`u32`]s `stride` bytes apart in slice of [`Self::swap_size()`]
+    /// at big-endian `offset` endian-independently.
+    fn iter_u24_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, u32>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::u24, offset, stride, count)
+    }
+    /// Iterates `count` contiguous [`u32`]s in slice of [`Self::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    fn iter_u32(&self, offset: usize, count: usize) -> InSituIter<'_, Self, u32>
+    where
+        Self: Sized,
+    {
+        self.iter_u32_strided(offset, U32, count)
+    }
+    /// Iterates `count` [`u32`]s `stride` bytes apart in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_u32_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, u32>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::u32, offset, stride, count)
+    }
+    /// Iterates `count` contiguous [`u64`]s in slice of [`Self::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    fn iter_u64(&self, offset: usize, count: usize) -> InSituIter<'_, Self, u64>
+    where
+        Self: Sized,
+    {
+        self.iter_u64_strided(offset, U64, count)
+    }
+    /// Iterates `count` [`u64`]s `stride` bytes apart in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_u64_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, u64>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::u64, offset, stride, count)
+    }
+    /// Iterates `count` contiguous [`u128`]s in slice of [`Self::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    fn iter_u128(&self, offset: usize, count: usize) -> InSituIter<'_, Self, u128>
+    where
+        Self: Sized,
+    {
+        self.iter_u128_strided(offset, U128, count)
+    }
+    /// Iterates `count` [`u128`]s `stride` bytes apart in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_u128_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, u128>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::u128, offset, stride, count)
+    }
+    /// Iterates `count` contiguous [`i8`]s in slice of [`Self::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    fn iter_i8(&self, offset: usize, count: usize) -> InSituIter<'_, Self, i8>
+    where
+        Self: Sized,
+    {
+        self.iter_i8_strided(offset, I8, count)
+    }
+    /// Iterates `count` [`i8`]s `stride` bytes apart in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_i8_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, i8>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::i8, offset, stride, count)
+    }
+    /// Iterates `count` contiguous [`i16`]s in slice of [`Self::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    fn iter_i16(&self, offset: usize, count: usize) -> InSituIter<'_, Self, i16>
+    where
+        Self: Sized,
+    {
+        self.iter_i16_strided(offset, I16, count)
+    }
+    /// Iterates `count` [`i16`]s `stride` bytes apart in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_i16_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, i16>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::i16, offset, stride, count)
+    }
+    /// Iterates `count` contiguous `i24`s as [`i32`]s in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_i24(&self, offset: usize, count: usize) -> InSituIter<'_, Self, i32>
+    where
+        Self: Sized,
+    {
+        self.iter_i24_strided(offset, I24, count)
+    }
+    /// Iterates `count` `i24`s as [`i32`]s `stride` bytes apart in slice of [`Self::swap_size()`]
+    /// at big-endian `offset` endian-independently.
+    fn iter_i24_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, i32>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::i24, offset, stride, count)
+    }
+    /// Iterates `count` contiguous [`i32`]s in slice of [`Self::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    fn iter_i32(&self, offset: usize, count: usize) -> InSituIter<'_, Self, i32>
+    where
+        Self: Sized,
+    {
+        self.iter_i32_strided(offset, I32, count)
+    }
+    /// Iterates `count` [`i32`]s `stride` bytes apart in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_i32_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, i32>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::i32, offset, stride, count)
+    }
+    /// Iterates `count` contiguous [`i64`]s in slice of [`Self::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    fn iter_i64(&self, offset: usize, count: usize) -> InSituIter<'_, Self, i64>
+    where
+        Self: Sized,
+    {
+        self.iter_i64_strided(offset, I64, count)
+    }
+    /// Iterates `count` [`i64`]s `stride` bytes apart in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_i64_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, i64>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::i64, offset, stride, count)
+    }
+    /// Iterates `count` contiguous [`i128`]s in slice of [`Self::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    fn iter_i128(&self, offset: usize, count: usize) -> InSituIter<'_, Self, i128>
+    where
+        Self: Sized,
+    {
+        self.iter_i128_strided(offset, I128, count)
+    }
+    /// Iterates `count` [`i128`]s `stride` bytes apart in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_i128_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, i128>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::i128, offset, stride, count)
+    }
+    /// Iterates `count` contiguous [`f32`]s in slice of [`Self::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    fn iter_f32(&self, offset: usize, count: usize) -> InSituIter<'_, Self, f32>
+    where
+        Self: Sized,
+    {
+        self.iter_f32_strided(offset, F32, count)
+    }
+    /// Iterates `count` [`f32`]s `stride` bytes apart in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_f32_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, f32>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::f32, offset, stride, count)
+    }
+    /// Iterates `count` contiguous [`f64`]s in slice of [`Self::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    fn iter_f64(&self, offset: usize, count: usize) -> InSituIter<'_, Self, f64>
+    where
+        Self: Sized,
+    {
+        self.iter_f64_strided(offset, F64, count)
+    }
+    /// Iterates `count` [`f64`]s `stride` bytes apart in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    fn iter_f64_strided(
+        &self,
+        offset: usize,
+        stride: usize,
+        count: usize,
+    ) -> InSituIter<'_, Self, f64>
+    where
+        Self: Sized,
+    {
+        InSituIter::new(self, Self::f64, offset, stride, count)
+    }
+
+    /// Gets [`InSituPrimitive`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    fn get<T: InSituPrimitive>(&self, offset: usize) -> T
+    where
+        Self: Sized,
+    {
+        T::get(self, offset)
+    }
+    /// Tries to get [`InSituPrimitive`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_get<T: InSituPrimitive>(&self, offset: usize) -> Result<T, Error>
+    where
+        Self: Sized,
+    {
+        T::try_get(self, offset)
+    }
+    /// Casts `count` words of [`InSituCast`] type at big-endian `offset` into slice
+    /// endian-independently.
+    ///
+    /// Borrows the words in place if they are aligned and need neither byte swapping nor offset
+    /// translation, i.e., if [`Self::is_native()`] and [`Self::at()`] passes through their offsets.
+    /// Otherwise collects them via [`InSituPrimitive::try_get()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice and [`Error::Crossing`] if a
+    /// word crosses a slice boundary.
+    #[cfg(feature = "alloc")]
+    fn cast_slice<T: InSituCast>(&self, offset: usize, count: usize) -> Result<Cow<'_, [T]>, Error>
+    where
+        Self: Sized,
+    {
+        let bytes = self.as_ref();
+        bounds(bytes, offset, count.saturating_mul(T::SIZE))?;
+        if (T::SIZE == U8 || self.is_native()) && (self.is_be() || self.swap_size() <= T::SIZE) {
+            self.try_at(offset, T::SIZE)?;
+            if let Some(slice) = cast::slice(&bytes[offset..], count) {
+                return Ok(Cow::Borrowed(slice));
+            }
+        }
+        (0..count)
+            .map(|index| T::try_get(self, offset + index * T::SIZE))
+            .collect::<Result<_, _>>()
+            .map(Cow::Owned)
+    }
+    /// Gets `#[repr(C)]` struct `T` at `offset` in place via [`bytemuck`].
+    ///
+    /// The fields of `T` are in native byte order, so the view must be native and must not
+    /// translate offsets, i.e., [`Self::is_native()`] and either [`Self::is_be()`] or a
+    /// [`Self::swap_size()`] not above one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotNative`] if the view is not native or translates offsets,
+    /// [`Error::OutOfBounds`] if `T` exceeds the slice, and [`Error::Misaligned`] if `T` is not
+    /// aligned.
+    #[cfg(feature = "bytemuck")]
+    fn bytemuck_ref<T: bytemuck::AnyBitPattern>(&self, offset: usize) -> Result<&T, Error>
+    where
+        Self: Sized,
+    {
+        cast::native(self)?;
+        let bytes = cast::bytes(self.as_ref(), offset, size_of::<T>())?;
+        bytemuck::try_from_bytes(bytes).map_err(|_| Error::Misaligned {
+            offset,
+            align: align_of::<T>(),
+        })
+    }
+    /// Gets `#[repr(C)]` struct `T` at `offset` in place via [`zerocopy`].
+    ///
+    /// The fields of `T` are in native byte order, so the view must be native and must not
+    /// translate offsets, i.e., [`Self::is_native()`] and either [`Self::is_be()`] or a
+    /// [`Self::swap_size()`] not above one.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotNative`] if the view is not native or translates offsets,
+    /// [`Error::OutOfBounds`] if `T` exceeds the slice, and [`Error::Misaligned`] if `T` is not
+    /// aligned.
+    #[cfg(feature = "zerocopy")]
+    fn zerocopy_ref<T>(&self, offset: usize) -> Result<&T, Error>
+    where
+        Self: Sized,
+        T: zerocopy::FromBytes + zerocopy::KnownLayout + zerocopy::Immutable,
+    {
+        cast::native(self)?;
+        let bytes = cast::bytes(self.as_ref(), offset, size_of::<T>())?;
+        T::ref_from_bytes(bytes).map_err(|_| Error::Misaligned {
+            offset,
+            align: align_of::<T>(),
+        })
+    }
+    /// Creates [`Hexdump`] of the bytes implementing [`core::fmt::Display`].
+    ///
+    /// Annotate named field ranges via [`Hexdump::fields()`].
+    fn hexdump(&self) -> Hexdump<'_, Self, Scope> {
+        Hexdump::new(self)
+    }
+    /// Computes internet checksum of the bytes in `range` as in RFC 1071.
+    ///
+    /// Sums the bytes as 16-bit words of [`Self::is_be()`] byte order, so the checksum is to be set
+    /// via [`InSituMut::set_u16()`] or [`InSituMut::update_checksum_u16()`]. Pads an odd trailing
+    /// byte with zero. The checksum of a range including a valid checksum is zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `range` exceeds the slice.
+    fn internet_checksum(&self, range: Range<usize>) -> Result<u16, Error> {
+        self.internet_checksum_with(range, &[])
+    }
+    /// Computes internet checksum of the bytes in `range` like [`Self::internet_checksum()`] but
+    /// prepended with `pseudo_header` bytes, e.g., the IP pseudo-header of TCP and UDP.
+    ///
+    /// The `pseudo_header` bytes are in the same byte order and of even length.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `range` exceeds the slice.
+    fn internet_checksum_with(
+        &self,
+        range: Range<usize>,
+        pseudo_header: &[u8],
+    ) -> Result<u16, Error> {
+        let bytes = checksum::range(self.as_ref(), range)?;
+        let sum = checksum::ones(0, pseudo_header, self.is_be());
+        Ok(!checksum::fold(checksum::ones(sum, bytes, self.is_be())))
+    }
+    /// Computes CRC-32 (ISO-HDLC) of the bytes in `range` as used by Ethernet, ZIP, and PNG.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `range` exceeds the slice.
+    fn crc32(&self, range: Range<usize>) -> Result<u32, Error> {
+        checksum::range(self.as_ref(), range).map(checksum::crc32)
+    }
+    /// Computes CRC-16 of `poly` of the bytes in `range`.
+    ///
+    /// Processes the bytes most significant bit first with zero initial value and no final XOR,
+    /// e.g., CRC-16/XMODEM for `poly = 0x1021` and CRC-16/UMTS for `poly = 0x8005`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `range` exceeds the slice.
+    fn crc16(&self, range: Range<usize>, poly: u16) -> Result<u16, Error> {
+        checksum::range(self.as_ref(), range).map(|bytes| checksum::crc16(bytes, poly))
+    }
+    /// Computes Adler-32 of the bytes in `range` as used by zlib.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if `range` exceeds the slice.
+    fn adler32(&self, range: Range<usize>) -> Result<u32, Error> {
+        checksum::range(self.as_ref(), range).map(checksum::adler32)
+    }
+    /// Tries to get [`&str`] if UTF-8 in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the string exceeds the slice and [`Error::Utf8`] if the
+    /// slice is not UTF-8.
+    fn try_utf8(&self, offset: usize, length: usize) -> Result<&str, Error> {
+        bounds(self.as_ref(), offset, length)?;
+        Ok(self.utf8(offset, length)?)
+    }
+    /// Tries to get [`BStr`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the string exceeds the slice.
+    #[cfg(feature = "bstr")]
+    fn try_bstr(&self, offset: usize, length: usize) -> Result<&BStr, Error> {
+        bounds(self.as_ref(), offset, length)?;
+        Ok(self.bstr(offset, length))
+    }
+    /// Tries to get [`bool`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_bool(&self, offset: usize) -> Result<bool, Error> {
+        bounds(self.as_ref(), self.try_at(offset, U8)?, U8)?;
+        Ok(self.bool(offset))
+    }
+    /// Tries to get [`u8`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_u8(&self, offset: usize) -> Result<u8, Error> {
+        bounds(self.as_ref(), self.try_at(offset, U8)?, U8)?;
+        Ok(self.u8(offset))
+    }
+    /// Tries to get [`u16`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_u16(&self, offset: usize) -> Result<u16, Error> {
+        bounds(self.as_ref(), self.try_at(offset, U16)?, U16)?;
+        Ok(self.u16(offset))
+    }
+    /// Tries to get `u24` as [`u32`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSwap`] if the view is word-swapped and [`Error::OutOfBounds`] if the
+    /// word exceeds the slice.
+    fn try_u24(&self, offset: usize) -> Result<u32, Error> {
+        word_swap(U24, self.is_word_swapped())?;
+        bounds(self.as_ref(), self.try_at(offset, U24)?, U24)?;
+        Ok(self.u24(offset))
+    }
+    /// Tries to get [`u32`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_u32(&self, offset: usize) -> Result<u32, Error> {
+        bounds(self.as_ref(), self.try_at(offset, U32)?, U32)?;
+        Ok(self.u32(offset))
+    }
+    /// Tries to get [`u64`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_u64(&self, offset: usize) -> Result<u64, Error> {
+        bounds(self.as_ref(), self.try_at(offset, U64)?, U64)?;
+        Ok(self.u64(offset))
+    }
+    /// Tries to get [`u128`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_u128(&self, offset: usize) -> Result<u128, Error> {
+        bounds(self.as_ref(), self.try_at(offset, U128)?, U128)?;
+        Ok(self.u128(offset))
+    }
+    /// Tries to get unsigned integer of `word_size <= 8` in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8`, [`Error::WordSwap`] if
+    /// the view is word-swapped and `word_size` is odd, and [`Error::OutOfBounds`] if the word
+    /// exceeds the slice.
+    fn try_uint(&self, offset: usize, word_size: usize) -> Result<u64, Error> {
+        word(word_size, U64)?;
+        word_swap(word_size, self.is_word_swapped())?;
+        bounds(self.as_ref(), self.try_at(offset, word_size)?, word_size)?;
+        Ok(self.uint(offset, word_size))
+    }
+    /// Tries to get unsigned integer of `word_size <= 16` in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16`, [`Error::WordSwap`] if
+    /// the view is word-swapped and `word_size` is odd, and [`Error::OutOfBounds`] if the word
+    /// exceeds the slice.
+    fn try_uint128(&self, offset: usize, word_size: usize) -> Result<u128, Error> {
+        word(word_size, U128)?;
+        word_swap(word_size, self.is_word_swapped())?;
+        bounds(self.as_ref(), self.try_at(offset, word_size)?, word_size)?;
+        Ok(self.uint128(offset, word_size))
+    }
+    /// Tries to get [`i8`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_i8(&self, offset: usize) -> Result<i8, Error> {
+        bounds(self.as_ref(), self.try_at(offset, I8)?, I8)?;
+        Ok(self.i8(offset))
+    }
+    /// Tries to get [`i16`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_i16(&self, offset: usize) -> Result<i16, Error> {
+        bounds(self.as_ref(), self.try_at(offset, I16)?, I16)?;
+        Ok(self.i16(offset))
+    }
+    /// Tries to get `i24` as [`i32`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSwap`] if the view is word-swapped and [`Error::OutOfBounds`] if the
+    /// word exceeds the slice.
+    fn try_i24(&self, offset: usize) -> Result<i32, Error> {
+        word_swap(I24, self.is_word_swapped())?;
+        bounds(self.as_ref(), self.try_at(offset, I24)?, I24)?;
+        Ok(self.i24(offset))
+    }
+    /// Tries to get [`i32`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_i32(&self, offset: usize) -> Result<i32, Error> {
+        bounds(self.as_ref(), self.try_at(offset, I32)?, I32)?;
+        Ok(self.i32(offset))
+    }
+    /// Tries to get [`i64`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_i64(&self, offset: usize) -> Result<i64, Error> {
+        bounds(self.as_ref(), self.try_at(offset, I64)?, I64)?;
+        Ok(self.i64(offset))
+    }
+    /// Tries to get [`i128`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_i128(&self, offset: usize) -> Result<i128, Error> {
+        bounds(self.as_ref(), self.try_at(offset, I128)?, I128)?;
+        Ok(self.i128(offset))
+    }
+    /// Tries to get signed integer of `word_size <= 8` in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8`, [`Error::WordSwap`] if
+    /// the view is word-swapped and `word_size` is odd, and [`Error::OutOfBounds`] if the word
+    /// exceeds the slice.
+    fn try_int(&self, offset: usize, word_size: usize) -> Result<i64, Error> {
+        word(word_size, I64)?;
+        word_swap(word_size, self.is_word_swapped())?;
+        bounds(self.as_ref(), self.try_at(offset, word_size)?, word_size)?;
+        Ok(self.int(offset, word_size))
+    }
+    /// Tries to get signed integer of `word_size <= 16` in slice of [`Self::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16`, [`Error::WordSwap`] if
+    /// the view is word-swapped and `word_size` is odd, and [`Error::OutOfBounds`] if the word
+    /// exceeds the slice.
+    fn try_int128(&self, offset: usize, word_size: usize) -> Result<i128, Error> {
+        word(word_size, I128)?;
+        word_swap(word_size, self.is_word_swapped())?;
+        bounds(self.as_ref(), self.try_at(offset, word_size)?, word_size)?;
+        Ok(self.int128(offset, word_size))
+    }
+    /// Tries to get [`f32`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_f32(&self, offset: usize) -> Result<f32, Error> {
+        bounds(self.as_ref(), self.try_at(offset, F32)?, F32)?;
+        Ok(self.f32(offset))
+    }
+    /// Tries to get [`f64`] in slice of [`Self::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_f64(&self, offset: usize) -> Result<f64, Error> {
+        bounds(self.as_ref(), self.try_at(offset, F64)?, F64)?;
+        Ok(self.f64(offset))
+    }
+}
+
+/// Provides endian-independent mutable bytes access.
+///
+/// Requires <code>[InSitu]\<Scope\></code> trait to know about endianness.
+/// <code>[InSituMut]\<Scope\></code> is **not (yet)** auto-implemented for all
+/// <code>[InSitu]\<Scope\> + [AsMut]\<\[[u8]\]\></code> implementors as the trait name would leak
+/// into the documentation under blanket implementations even for a private generic type parameter
+/// `Scope`. This might be resolved in the far future by a language extension supporting scoped
+/// trait implementations or by fixing *rustdoc* if there are no loopholes which would allow to
+/// actually use the trait.
+pub trait InSituMut<Scope = ()>: InSitu<Scope> + AsMut<[u8]> {
+    /// Sets [`&str`] as UTF-8 at big-endian `offset` with its bytes swapped via [`InSitu::at()`].
+    ///
+    /// Reverses `InSitu::utf8_unswapped()`, i.e., each byte is written like [`Self::set_u8()`].
+    fn set_utf8(&mut self, offset: usize, value: &str) {
+        if self.is_be() || self.swap_size() <= U8 {
+            self.as_mut()[offset..][..value.len()].copy_from_slice(value.as_bytes());
+        } else {
+            for (offset, &byte) in (offset..).zip(value.as_bytes()) {
+                self.set_u8(offset, byte);
+            }
+        }
+    }
+    /// Sets [`bool`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    fn set_bool(&mut self, offset: usize, value: bool) {
+        self.set_u8(offset, value.into());
+    }
+    /// Sets [`u8`] in slice of [`InSitu::swap_size()`] at big-endian `offset` endian-independently.
+    fn set_u8(&mut self, offset: usize, value: u8) {
+        let at = self.at(offset, U8);
+        self.as_mut()[at] = value;
+    }
+    /// Sets [`u16`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    fn set_u16(&mut self, offset: usize, value: u16) {
+        let offset = self.at(offset, U16);
+        if self.is_be() {
+            BE::write_u16(&mut self.as_mut()[offset..], value);
+        } else {
+            LE::write_u16(&mut self.as_mut()[offset..], value);
+        }
+    }
+    /// Sets `u24` as [`u32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`InSitu::is_word_swapped()`] since the word is of odd size, see
+    /// [`Self::try_set_u24()`].
+    fn set_u24(&mut self, offset: usize, value: u32) {
+        if self.is_word_swapped() {
+            return self.set_uint128(offset, value.into(), U24);
+        }
+        let offset = self.at(offset, U24);
+        if self.is_be() {
+            BE::write_u24(&mut self.as_mut()[offset..], value);
+        } else {
+            LE::write_u24(&mut self.as_mut()[offset..], value);
+        }
+    }
+    /// Sets [`u32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    fn set_u32(&mut self, offset: usize, value: u32) {
+        let offset = self.at(offset, U32);
+        let value = if self.is_word_swapped() {
+            value.rotate_left(16)
+        } else {
+            value
+        };
+        if self.is_be() {
+            BE::write_u32(&mut self.as_mut()[offset..], value);
+        } else {
+            LE::write_u32(&mut self.as_mut()[offset..], value);
+        }
+    }
+    /// Sets [`u64`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    fn set_u64(&mut self, offset: usize, value: u64) {
+        let offset = self.at(offset, U64);
+        let value = if self.is_word_swapped() {
+            swap_words_u64(value)
+        } else {
+            value
+        };
+        if self.is_be() {
+            BE::write_u64(&mut self.as_mut()[offset..], value);
+        } else {
+            LE::write_u64(&mut self.as_mut()[offset..], value);
+        }
+    }
+    /// Sets [`u128`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    fn set_u128(&mut self, offset: usize, value: u128) {
+        let offset = self.at(offset, U128);
+        let value = if self.is_word_swapped() {
+            swap_words_u128(value)
+        } else {
+            value
+        };
+        if self.is_be() {
+            BE::write_u128(&mut self.as_mut()[offset..], value);
+        } else {
+            LE::write_u128(&mut self.as_mut()[offset..], value);
+        }
+    }
+    /// Sets unsigned integer of `word_size <= 8` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`InSitu::is_word_swapped()`] and `word_size` is odd and above two bytes, see
+    /// [`Self::try_set_uint()`].
+    fn set_uint(&mut self, offset: usize, value: u64, word_size: usize) {
+        if self.is_word_swapped() {
+            return self.set_uint128(offset, value.into(), word_size);
+        }
+        let offset = self.at(offset, word_size);
+        if self.is_be() {
+            BE::write_uint(&mut self.as_mut()[offset..], value, word_size);
+        } else {
+            LE::write_uint(&mut self.as_mut()[offset..], value, word_size);
+        }
+    }
+    /// Sets unsigned integer of `word_size <= 16` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`InSitu::is_word_swapped()`] and `word_size` is odd and above two bytes, see
+    /// [`Self::try_set_uint128()`].
+    fn set_uint128(&mut self, offset: usize, value: u128, word_size: usize) {
+        let offset = self.at(offset, word_size);
+        let value = if self.is_word_swapped() {
+            assert!(value <= umax(word_size), "value does not fit into word");
+            swap_words_uint128(value, word_size)
+        } else {
+            value
+        };
+        if self.is_be() {
+            BE::write_uint128(&mut self.as_mut()[offset..], value, word_size);
+        } else {
+            LE::write_uint128(&mut self.as_mut()[offset..], value, word_size);
+        }
+    }
+    /// Sets [`i8`] in slice of [`InSitu::swap_size()`] at big-endian `offset` endian-independently.
+    #[allow(clippy::cast_sign_loss)]
+    fn set_i8(&mut self, offset: usize, value: i8) {
+        let at = self.at(offset, I8);
+        self.as_mut()[at] = value as u8;
+    }
+    /// Sets [`i16`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    fn set_i16(&mut self, offset: usize, value: i16) {
+        let offset = self.at(offset, I16);
+        if self.is_be() {
+            BE::write_i16(&mut self.as_mut()[offset..], value);
+        } else {
+            LE::write_i16(&mut self.as_mut()[offset..], value);
+        }
+    }
+    /// Sets `i24` as [`i32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`InSitu::is_word_swapped()`] since the word is of odd size, see
+    /// [`Self::try_set_i24()`].
+    fn set_i24(&mut self, offset: usize, value: i32) {
+        if self.is_word_swapped() {
+            return self.set_int128(offset, value.into(), I24);
+        }
+        let offset = self.at(offset, I24);
+        if self.is_be() {
+            BE::write_i24(&mut self.as_mut()[offset..], value);
+        } else {
+            LE::write_i24(&mut self.as_mut()[offset..], value);
+        }
+    }
+    /// Sets [`i32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    #[allow(clippy::cast_sign_loss)]
+    fn set_i32(&mut self, offset: usize, value: i32) {
+        if self.is_word_swapped() {
+            self.set_u32(offset, value as u32);
+            return;
+        }
+        let offset = self.at(offset, I32);
+        if self.is_be() {
+            BE::write_i32(&mut self.as_mut()[offset..], value);
+        } else {
+            LE::write_i32(&mut self.as_mut()[offset..], value);
+        }
+    }
+    /// Sets [`i64]` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    #[allow(clippy::cast_sign_loss)]
+    fn set_i64(&mut self, offset: usize, value: i64) {
+        if self.is_word_swapped() {
+            self.set_u64(offset, value as u64);
+            return;
+        }
+        let offset = self.at(offset, I64);
+        if self.is_be() {
+            BE::write_i64(&mut self.as_mut()[offset..], value);
+        } else {
+            LE::write_i64(&mut self.as_mut()[offset..], value);
+        }
+    }
+    /// Sets [`i128`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    #[allow(clippy::cast_sign_loss)]
+    fn set_i128(&mut self, offset: usize, value: i128) {
+        if self.is_word_swapped() {
+            self.set_u128(offset, value as u128);
+            return;
+        }
+        let offset = self.at(offset, I128);
+        if self.is_be() {
+            BE::write_i128(&mut self.as_mut()[offset..], value);
+        } else {
+            LE::write_i128(&mut self.as_mut()[offset..], value);
+        }
+    }
+    /// Sets signed integer of `word_size <= 8` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`InSitu::is_word_swapped()`] and `word_size` is odd and above two bytes, see
+    /// [`Self::try_set_int()`].
+    fn set_int(&mut self, offset: usize, value: i64, word_size: usize) {
+        if self.is_word_swapped() {
+            return self.set_int128(offset, value.into(), word_size);
+        }
+        let offset = self.at(offset, word_size);
         if self.is_be() {
             BE::write_int(&mut self.as_mut()[offset..], value, word_size);
         } else {
             LE::write_int(&mut self.as_mut()[offset..], value, word_size);
         }
     }
-    /// Sets signed integer of `word_size <= 16` in slice of [`InSitu::swap_size()`] at big-endian
-    /// `offset` endian-independently.
-    fn set_int128(&mut self, offset: usize, value: i128, word_size: usize) {
-        let offset = self.at(offset, word_size);
-        if self.is_be() {
-            BE::write_int128(&mut self.as_mut()[offset..], value, word_size);
-        } else {
-            LE::write_int128(&mut self.as_mut()[offset..], value, word_size);
+    /// Sets signed integer of `word_size <= 16` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`InSitu::is_word_swapped()`] and `word_size` is odd and above two bytes, see
+    /// [`Self::try_set_int128()`].
+    #[allow(clippy::cast_sign_loss)]
+    fn set_int128(&mut self, offset: usize, value: i128, word_size: usize) {
+        if self.is_word_swapped() {
+            let value = value as u128 & umax(word_size);
+            return self.set_uint128(offset, value, word_size);
+        }
+        let offset = self.at(offset, word_size);
+        if self.is_be() {
+            BE::write_int128(&mut self.as_mut()[offset..], value, word_size);
+        } else {
+            LE::write_int128(&mut self.as_mut()[offset..], value, word_size);
+        }
+    }
+    /// Sets [`f32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    fn set_f32(&mut self, offset: usize, value: f32) {
+        if self.is_word_swapped() {
+            self.set_u32(offset, value.to_bits());
+            return;
+        }
+        let offset = self.at(offset, F32);
+        if self.is_be() {
+            BE::write_f32(&mut self.as_mut()[offset..], value);
+        } else {
+            LE::write_f32(&mut self.as_mut()[offset..], value);
+        }
+    }
+    /// Sets [`f64`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    fn set_f64(&mut self, offset: usize, value: f64) {
+        if self.is_word_swapped() {
+            self.set_u64(offset, value.to_bits());
+            return;
+        }
+        let offset = self.at(offset, F64);
+        if self.is_be() {
+            BE::write_f64(&mut self.as_mut()[offset..], value);
+        } else {
+            LE::write_f64(&mut self.as_mut()[offset..], value);
+        }
+    }
+    /// Sets [`u16`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`InSitu::is_native()`].
+    /// Falls back to setting word by word if [`InSitu::at()`] translates the offsets or if the
+    /// words are swapped.
+    fn set_u16_from_slice(&mut self, offset: usize, src: &[u16]) {
+        if self.is_le() && self.swap_size() > U16 {
+            for (index, &value) in src.iter().enumerate() {
+                self.set_u16(offset + index * U16, value);
+            }
+        } else {
+            let is_be = self.is_be();
+            let dst = &mut self.as_mut()[offset..][..src.len() * U16];
+            if is_be {
+                BE::write_u16_into(src, dst);
+            } else {
+                LE::write_u16_into(src, dst);
+            }
+        }
+    }
+    /// Sets [`u32`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`InSitu::is_native()`].
+    /// Falls back to setting word by word if [`InSitu::at()`] translates the offsets or if the
+    /// words are swapped.
+    fn set_u32_from_slice(&mut self, offset: usize, src: &[u32]) {
+        if (self.is_le() && self.swap_size() > U32) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.set_u32(offset + index * U32, value);
+            }
+        } else {
+            let is_be = self.is_be();
+            let dst = &mut self.as_mut()[offset..][..src.len() * U32];
+            if is_be {
+                BE::write_u32_into(src, dst);
+            } else {
+                LE::write_u32_into(src, dst);
+            }
+        }
+    }
+    /// Sets [`u64`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`InSitu::is_native()`].
+    /// Falls back to setting word by word if [`InSitu::at()`] translates the offsets or if the
+    /// words are swapped.
+    fn set_u64_from_slice(&mut self, offset: usize, src: &[u64]) {
+        if (self.is_le() && self.swap_size() > U64) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.set_u64(offset + index * U64, value);
+            }
+        } else {
+            let is_be = self.is_be();
+            let dst = &mut self.as_mut()[offset..][..src.len() * U64];
+            if is_be {
+                BE::write_u64_into(src, dst);
+            } else {
+                LE::write_u64_into(src, dst);
+            }
+        }
+    }
+    /// Sets [`u128`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`InSitu::is_native()`].
+    /// Falls back to setting word by word if [`InSitu::at()`] translates the offsets or if the
+    /// words are swapped.
+    fn set_u128_from_slice(&mut self, offset: usize, src: &[u128]) {
+        if (self.is_le() && self.swap_size() > U128) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.set_u128(offset + index * U128, value);
+            }
+        } else {
+            let is_be = self.is_be();
+            let dst = &mut self.as_mut()[offset..][..src.len() * U128];
+            if is_be {
+                BE::write_u128_into(src, dst);
+            } else {
+                LE::write_u128_into(src, dst);
+            }
+        }
+    }
+    /// Sets [`i16`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`InSitu::is_native()`].
+    /// Falls back to setting word by word if [`InSitu::at()`] translates the offsets or if the
+    /// words are swapped.
+    fn set_i16_from_slice(&mut self, offset: usize, src: &[i16]) {
+        if self.is_le() && self.swap_size() > I16 {
+            for (index, &value) in src.iter().enumerate() {
+                self.set_i16(offset + index * I16, value);
+            }
+        } else {
+            let is_be = self.is_be();
+            let dst = &mut self.as_mut()[offset..][..src.len() * I16];
+            if is_be {
+                BE::write_i16_into(src, dst);
+            } else {
+                LE::write_i16_into(src, dst);
+            }
+        }
+    }
+    /// Sets [`i32`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`InSitu::is_native()`].
+    /// Falls back to setting word by word if [`InSitu::at()`] translates the offsets or if the
+    /// words are swapped.
+    fn set_i32_from_slice(&mut self, offset: usize, src: &[i32]) {
+        if (self.is_le() && self.swap_size() > I32) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.set_i32(offset + index * I32, value);
+            }
+        } else {
+            let is_be = self.is_be();
+            let dst = &mut self.as_mut()[offset..][..src.len() * I32];
+            if is_be {
+                BE::write_i32_into(src, dst);
+            } else {
+                LE::write_i32_into(src, dst);
+            }
+        }
+    }
+    /// Sets [`i64`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`InSitu::is_native()`].
+    /// Falls back to setting word by word if [`InSitu::at()`] translates the offsets or if the
+    /// words are swapped.
+    fn set_i64_from_slice(&mut self, offset: usize, src: &[i64]) {
+        if (self.is_le() && self.swap_size() > I64) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.set_i64(offset + index * I64, value);
+            }
+        } else {
+            let is_be = self.is_be();
+            let dst = &mut self.as_mut()[offset..][..src.len() * I64];
+            if is_be {
+                BE::write_i64_into(src, dst);
+            } else {
+                LE::write_i64_into(src, dst);
+            }
+        }
+    }
+    /// Sets [`i128`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`InSitu::is_native()`].
+    /// Falls back to setting word by word if [`InSitu::at()`] translates the offsets or if the
+    /// words are swapped.
+    fn set_i128_from_slice(&mut self, offset: usize, src: &[i128]) {
+        if (self.is_le() && self.swap_size() > I128) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.set_i128(offset + index * I128, value);
+            }
+        } else {
+            let is_be = self.is_be();
+            let dst = &mut self.as_mut()[offset..][..src.len() * I128];
+            if is_be {
+                BE::write_i128_into(src, dst);
+            } else {
+                LE::write_i128_into(src, dst);
+            }
+        }
+    }
+    /// Sets [`f32`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`InSitu::is_native()`].
+    /// Falls back to setting word by word if [`InSitu::at()`] translates the offsets or if the
+    /// words are swapped.
+    fn set_f32_from_slice(&mut self, offset: usize, src: &[f32]) {
+        if (self.is_le() && self.swap_size() > F32) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.set_f32(offset + index * F32, value);
+            }
+        } else {
+            let is_be = self.is_be();
+            let dst = &mut self.as_mut()[offset..][..src.len() * F32];
+            if is_be {
+                BE::write_f32_into(src, dst);
+            } else {
+                LE::write_f32_into(src, dst);
+            }
+        }
+    }
+    /// Sets [`f64`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// Copies the words at once and swaps their bytes in place unless [`InSitu::is_native()`].
+    /// Falls back to setting word by word if [`InSitu::at()`] translates the offsets or if the
+    /// words are swapped.
+    fn set_f64_from_slice(&mut self, offset: usize, src: &[f64]) {
+        if (self.is_le() && self.swap_size() > F64) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.set_f64(offset + index * F64, value);
+            }
+        } else {
+            let is_be = self.is_be();
+            let dst = &mut self.as_mut()[offset..][..src.len() * F64];
+            if is_be {
+                BE::write_f64_into(src, dst);
+            } else {
+                LE::write_f64_into(src, dst);
+            }
+        }
+    }
+    /// Tries to set [`u16`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case the slice might
+    /// be partially overwritten.
+    fn try_set_u16_from_slice(&mut self, offset: usize, src: &[u16]) -> Result<(), Error> {
+        if self.is_le() && self.swap_size() > U16 {
+            for (index, &value) in src.iter().enumerate() {
+                self.try_set_u16(offset + index * U16, value)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, src.len() * U16)?;
+            self.set_u16_from_slice(offset, src);
+        }
+        Ok(())
+    }
+    /// Tries to set [`u32`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case the slice might
+    /// be partially overwritten.
+    fn try_set_u32_from_slice(&mut self, offset: usize, src: &[u32]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > U32) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.try_set_u32(offset + index * U32, value)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, src.len() * U32)?;
+            self.set_u32_from_slice(offset, src);
+        }
+        Ok(())
+    }
+    /// Tries to set [`u64`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case the slice might
+    /// be partially overwritten.
+    fn try_set_u64_from_slice(&mut self, offset: usize, src: &[u64]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > U64) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.try_set_u64(offset + index * U64, value)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, src.len() * U64)?;
+            self.set_u64_from_slice(offset, src);
+        }
+        Ok(())
+    }
+    /// Tries to set [`u128`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case the slice might
+    /// be partially overwritten.
+    fn try_set_u128_from_slice(&mut self, offset: usize, src: &[u128]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > U128) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.try_set_u128(offset + index * U128, value)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, src.len() * U128)?;
+            self.set_u128_from_slice(offset, src);
+        }
+        Ok(())
+    }
+    /// Tries to set [`i16`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case the slice might
+    /// be partially overwritten.
+    fn try_set_i16_from_slice(&mut self, offset: usize, src: &[i16]) -> Result<(), Error> {
+        if self.is_le() && self.swap_size() > I16 {
+            for (index, &value) in src.iter().enumerate() {
+                self.try_set_i16(offset + index * I16, value)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, src.len() * I16)?;
+            self.set_i16_from_slice(offset, src);
+        }
+        Ok(())
+    }
+    /// Tries to set [`i32`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case the slice might
+    /// be partially overwritten.
+    fn try_set_i32_from_slice(&mut self, offset: usize, src: &[i32]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > I32) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.try_set_i32(offset + index * I32, value)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, src.len() * I32)?;
+            self.set_i32_from_slice(offset, src);
+        }
+        Ok(())
+    }
+    /// Tries to set [`i64`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case the slice might
+    /// be partially overwritten.
+    fn try_set_i64_from_slice(&mut self, offset: usize, src: &[i64]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > I64) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.try_set_i64(offset + index * I64, value)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, src.len() * I64)?;
+            self.set_i64_from_slice(offset, src);
+        }
+        Ok(())
+    }
+    /// Tries to set [`i128`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case the slice might
+    /// be partially overwritten.
+    fn try_set_i128_from_slice(&mut self, offset: usize, src: &[i128]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > I128) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.try_set_i128(offset + index * I128, value)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, src.len() * I128)?;
+            self.set_i128_from_slice(offset, src);
+        }
+        Ok(())
+    }
+    /// Tries to set [`f32`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case the slice might
+    /// be partially overwritten.
+    fn try_set_f32_from_slice(&mut self, offset: usize, src: &[f32]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > F32) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.try_set_f32(offset + index * F32, value)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, src.len() * F32)?;
+            self.set_f32_from_slice(offset, src);
+        }
+        Ok(())
+    }
+    /// Tries to set [`f64`]s from `src` in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the words exceed the slice, in which case the slice might
+    /// be partially overwritten.
+    fn try_set_f64_from_slice(&mut self, offset: usize, src: &[f64]) -> Result<(), Error> {
+        if (self.is_le() && self.swap_size() > F64) || self.is_word_swapped() {
+            for (index, &value) in src.iter().enumerate() {
+                self.try_set_f64(offset + index * F64, value)?;
+            }
+        } else {
+            bounds(self.as_ref(), offset, src.len() * F64)?;
+            self.set_f64_from_slice(offset, src);
+        }
+        Ok(())
+    }
+
+    /// Sets [`InSituPrimitive`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    fn set<T: InSituPrimitive>(&mut self, offset: usize, value: T)
+    where
+        Self: Sized,
+    {
+        T::set(self, offset, value);
+    }
+    /// Tries to set [`InSituPrimitive`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set<T: InSituPrimitive>(&mut self, offset: usize, value: T) -> Result<(), Error>
+    where
+        Self: Sized,
+    {
+        T::try_set(self, offset, value)
+    }
+    /// Gets `#[repr(C)]` struct `T` at `offset` in place via [`bytemuck`] mutably.
+    ///
+    /// The fields of `T` are in native byte order, see [`InSitu::bytemuck_ref()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotNative`] if the view is not native or translates offsets,
+    /// [`Error::OutOfBounds`] if `T` exceeds the slice, and [`Error::Misaligned`] if `T` is not
+    /// aligned.
+    #[cfg(feature = "bytemuck")]
+    fn bytemuck_mut<T>(&mut self, offset: usize) -> Result<&mut T, Error>
+    where
+        Self: Sized,
+        T: bytemuck::AnyBitPattern + bytemuck::NoUninit,
+    {
+        cast::native(self)?;
+        let bytes = cast::bytes_mut(self.as_mut(), offset, size_of::<T>())?;
+        bytemuck::try_from_bytes_mut(bytes).map_err(|_| Error::Misaligned {
+            offset,
+            align: align_of::<T>(),
+        })
+    }
+    /// Gets `#[repr(C)]` struct `T` at `offset` in place via [`zerocopy`] mutably.
+    ///
+    /// The fields of `T` are in native byte order, see [`InSitu::zerocopy_ref()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::NotNative`] if the view is not native or translates offsets,
+    /// [`Error::OutOfBounds`] if `T` exceeds the slice, and [`Error::Misaligned`] if `T` is not
+    /// aligned.
+    #[cfg(feature = "zerocopy")]
+    fn zerocopy_mut<T>(&mut self, offset: usize) -> Result<&mut T, Error>
+    where
+        Self: Sized,
+        T: zerocopy::FromBytes + zerocopy::IntoBytes + zerocopy::KnownLayout,
+    {
+        cast::native(self)?;
+        let bytes = cast::bytes_mut(self.as_mut(), offset, size_of::<T>())?;
+        T::mut_from_bytes(bytes).map_err(|_| Error::Misaligned {
+            offset,
+            align: align_of::<T>(),
+        })
+    }
+    /// Tries to set [`&str`] as UTF-8 at big-endian `offset` with its bytes swapped via
+    /// [`InSitu::at()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if a byte exceeds the slice, in which case nothing is set.
+    fn try_set_utf8(&mut self, offset: usize, value: &str) -> Result<(), Error> {
+        bounds(self.as_ref(), offset, value.len())?;
+        for offset in offset..offset + value.len() {
+            bounds(self.as_ref(), self.try_at(offset, U8)?, U8)?;
+        }
+        self.set_utf8(offset, value);
+        Ok(())
+    }
+    /// Sets [`&str`] as UTF-8 NUL-terminated in `max` bytes at big-endian `offset`.
+    ///
+    /// Truncates the string at a character boundary to leave room for the NUL terminator and fills
+    /// the remaining bytes with NUL. Returns the length of the string as set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the `max` bytes exceed the slice, in which case nothing is
+    /// set.
+    fn set_cstr(&mut self, offset: usize, max: usize, value: &str) -> Result<usize, Error> {
+        bounds(self.as_ref(), offset, max)?;
+        let value = truncated(value, max.saturating_sub(1));
+        padded(&mut self.as_mut()[offset..][..max], value.as_bytes(), 0);
+        Ok(value.len())
+    }
+    /// Sets [`&str`] as UTF-8 prefixed with its length as unsigned integer of `prefix_size <= 8` at
+    /// big-endian `offset` endian-independently.
+    ///
+    /// Truncates the string at a character boundary to the maximum length representable by the
+    /// prefix. Returns the length of the string as set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `prefix_size` is zero or exceeds `8` and
+    /// [`Error::OutOfBounds`] if the prefix or the string exceeds the slice, in which case nothing
+    /// is set.
+    fn set_pstr(&mut self, offset: usize, prefix_size: usize, value: &str) -> Result<usize, Error> {
+        word(prefix_size, U64)?;
+        let value = truncated(value, prefixed(prefix_size));
+        bounds(self.as_ref(), offset, prefix_size + value.len())?;
+        self.try_set_uint(offset, value.len() as u64, prefix_size)?;
+        self.as_mut()[offset + prefix_size..][..value.len()].copy_from_slice(value.as_bytes());
+        Ok(value.len())
+    }
+    /// Sets [`&str`] as UTF-8 in `width` bytes at big-endian `offset` padded with trailing `pad`
+    /// bytes, e.g., `b'\0'` or `b' '`.
+    ///
+    /// Truncates the string at a character boundary to `width` bytes. Returns the length of the
+    /// string as set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the `width` bytes exceed the slice, in which case nothing
+    /// is set.
+    fn set_padded_utf8(
+        &mut self,
+        offset: usize,
+        width: usize,
+        value: &str,
+        pad: u8,
+    ) -> Result<usize, Error> {
+        bounds(self.as_ref(), offset, width)?;
+        let value = truncated(value, width);
+        padded(&mut self.as_mut()[offset..][..width], value.as_bytes(), pad);
+        Ok(value.len())
+    }
+    /// Sets bytes NUL-terminated in `max` bytes at big-endian `offset`.
+    ///
+    /// Truncates the string to leave room for the NUL terminator and fills the remaining bytes
+    /// with NUL. Returns the length of the string as set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the `max` bytes exceed the slice, in which case nothing is
+    /// set.
+    #[cfg(feature = "bstr")]
+    fn set_cbstr(&mut self, offset: usize, max: usize, value: &[u8]) -> Result<usize, Error> {
+        bounds(self.as_ref(), offset, max)?;
+        let value = &value[..value.len().min(max.saturating_sub(1))];
+        padded(&mut self.as_mut()[offset..][..max], value, 0);
+        Ok(value.len())
+    }
+    /// Sets bytes prefixed with its length as unsigned integer of `prefix_size <= 8` at big-endian
+    /// `offset` endian-independently.
+    ///
+    /// Truncates the string to the maximum length representable by the prefix. Returns the
+    /// length of the string as set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `prefix_size` is zero or exceeds `8` and
+    /// [`Error::OutOfBounds`] if the prefix or the string exceeds the slice, in which case nothing
+    /// is set.
+    #[cfg(feature = "bstr")]
+    fn set_pbstr(
+        &mut self,
+        offset: usize,
+        prefix_size: usize,
+        value: &[u8],
+    ) -> Result<usize, Error> {
+        word(prefix_size, U64)?;
+        let value = &value[..value.len().min(prefixed(prefix_size))];
+        bounds(self.as_ref(), offset, prefix_size + value.len())?;
+        self.try_set_uint(offset, value.len() as u64, prefix_size)?;
+        self.as_mut()[offset + prefix_size..][..value.len()].copy_from_slice(value);
+        Ok(value.len())
+    }
+    /// Sets bytes in `width` bytes at big-endian `offset` padded with trailing `pad` bytes, e.g.,
+    /// `b'\0'` or `b' '`.
+    ///
+    /// Truncates the string to `width` bytes. Returns the length of the string as set.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the `width` bytes exceed the slice, in which case nothing
+    /// is set.
+    #[cfg(feature = "bstr")]
+    fn set_padded_bstr(
+        &mut self,
+        offset: usize,
+        width: usize,
+        value: &[u8],
+        pad: u8,
+    ) -> Result<usize, Error> {
+        bounds(self.as_ref(), offset, width)?;
+        let value = &value[..value.len().min(width)];
+        padded(&mut self.as_mut()[offset..][..width], value, pad);
+        Ok(value.len())
+    }
+    /// Sets [`&str`] as UTF-16 in `code_units` at big-endian `offset` endian-independently.
+    ///
+    /// Fills the remaining code units with zero.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the string does not fit into `code_units`, with `length`
+    /// being the end of the code units, or if a code unit exceeds the slice, in which case nothing
+    /// is set.
+    fn set_utf16(&mut self, offset: usize, code_units: usize, value: &str) -> Result<(), Error> {
+        let encoded = value.encode_utf16().count();
+        if encoded > code_units {
+            return Err(Error::OutOfBounds {
+                offset,
+                word_size: encoded.saturating_mul(U16),
+                length: offset.saturating_add(code_units.saturating_mul(U16)),
+            });
+        }
+        for offset in (offset..).step_by(U16).take(code_units) {
+            bounds(self.as_ref(), self.try_at(offset, U16)?, U16)?;
         }
+        let units = value.encode_utf16().chain(repeat(0));
+        for (offset, unit) in (offset..).step_by(U16).zip(units).take(code_units) {
+            self.set_u16(offset, unit);
+        }
+        Ok(())
     }
-    /// Sets [`f32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// Tries to set [`bool`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
     /// endian-independently.
-    fn set_f32(&mut self, offset: usize, value: f32) {
-        let offset = self.at(offset, F32);
-        if self.is_be() {
-            BE::write_f32(&mut self.as_mut()[offset..], value);
-        } else {
-            LE::write_f32(&mut self.as_mut()[offset..], value);
-        }
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set_bool(&mut self, offset: usize, value: bool) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, U8)?, U8)?;
+        self.set_bool(offset, value);
+        Ok(())
     }
-    /// Sets [`f64`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// Tries to set [`u8`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
     /// endian-independently.
-    fn set_f64(&mut self, offset: usize, value: f64) {
-        let offset = self.at(offset, F64);
-        if self.is_be() {
-            BE::write_f64(&mut self.as_mut()[offset..], value);
-        } else {
-            LE::write_f64(&mut self.as_mut()[offset..], value);
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set_u8(&mut self, offset: usize, value: u8) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, U8)?, U8)?;
+        self.set_u8(offset, value);
+        Ok(())
+    }
+    /// Tries to set [`u16`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set_u16(&mut self, offset: usize, value: u16) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, U16)?, U16)?;
+        self.set_u16(offset, value);
+        Ok(())
+    }
+    /// Tries to set `u24` as [`u32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSwap`] if the view is word-swapped and [`Error::OutOfBounds`] if the
+    /// word exceeds the slice.
+    fn try_set_u24(&mut self, offset: usize, value: u32) -> Result<(), Error> {
+        word_swap(U24, self.is_word_swapped())?;
+        bounds(self.as_ref(), self.try_at(offset, U24)?, U24)?;
+        self.set_u24(offset, value);
+        Ok(())
+    }
+    /// Tries to set [`u32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set_u32(&mut self, offset: usize, value: u32) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, U32)?, U32)?;
+        self.set_u32(offset, value);
+        Ok(())
+    }
+    /// Tries to set [`u64`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set_u64(&mut self, offset: usize, value: u64) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, U64)?, U64)?;
+        self.set_u64(offset, value);
+        Ok(())
+    }
+    /// Tries to set [`u128`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set_u128(&mut self, offset: usize, value: u128) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, U128)?, U128)?;
+        self.set_u128(offset, value);
+        Ok(())
+    }
+    /// Tries to set unsigned integer of `word_size <= 8` in slice of [`InSitu::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8`, [`Error::WordSwap`] if
+    /// the view is word-swapped and `word_size` is odd, and [`Error::OutOfBounds`] if the word
+    /// exceeds the slice.
+    fn try_set_uint(&mut self, offset: usize, value: u64, word_size: usize) -> Result<(), Error> {
+        word(word_size, U64)?;
+        word_swap(word_size, self.is_word_swapped())?;
+        bounds(self.as_ref(), self.try_at(offset, word_size)?, word_size)?;
+        self.set_uint(offset, value, word_size);
+        Ok(())
+    }
+    /// Tries to set unsigned integer of `word_size <= 16` in slice of [`InSitu::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16`, [`Error::WordSwap`] if
+    /// the view is word-swapped and `word_size` is odd, and [`Error::OutOfBounds`] if the word
+    /// exceeds the slice.
+    fn try_set_uint128(
+        &mut self,
+        offset: usize,
+        value: u128,
+        word_size: usize,
+    ) -> Result<(), Error> {
+        word(word_size, U128)?;
+        word_swap(word_size, self.is_word_swapped())?;
+        bounds(self.as_ref(), self.try_at(offset, word_size)?, word_size)?;
+        self.set_uint128(offset, value, word_size);
+        Ok(())
+    }
+    /// Tries to set [`i8`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set_i8(&mut self, offset: usize, value: i8) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, I8)?, I8)?;
+        self.set_i8(offset, value);
+        Ok(())
+    }
+    /// Tries to set [`i16`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set_i16(&mut self, offset: usize, value: i16) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, I16)?, I16)?;
+        self.set_i16(offset, value);
+        Ok(())
+    }
+    /// Tries to set `i24` as [`i32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSwap`] if the view is word-swapped and [`Error::OutOfBounds`] if the
+    /// word exceeds the slice.
+    fn try_set_i24(&mut self, offset: usize, value: i32) -> Result<(), Error> {
+        word_swap(I24, self.is_word_swapped())?;
+        bounds(self.as_ref(), self.try_at(offset, I24)?, I24)?;
+        self.set_i24(offset, value);
+        Ok(())
+    }
+    /// Tries to set [`i32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set_i32(&mut self, offset: usize, value: i32) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, I32)?, I32)?;
+        self.set_i32(offset, value);
+        Ok(())
+    }
+    /// Tries to set [`i64`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set_i64(&mut self, offset: usize, value: i64) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, I64)?, I64)?;
+        self.set_i64(offset, value);
+        Ok(())
+    }
+    /// Tries to set [`i128`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set_i128(&mut self, offset: usize, value: i128) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, I128)?, I128)?;
+        self.set_i128(offset, value);
+        Ok(())
+    }
+    /// Tries to set signed integer of `word_size <= 8` in slice of [`InSitu::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8`, [`Error::WordSwap`] if
+    /// the view is word-swapped and `word_size` is odd, and [`Error::OutOfBounds`] if the word
+    /// exceeds the slice.
+    fn try_set_int(&mut self, offset: usize, value: i64, word_size: usize) -> Result<(), Error> {
+        word(word_size, I64)?;
+        word_swap(word_size, self.is_word_swapped())?;
+        bounds(self.as_ref(), self.try_at(offset, word_size)?, word_size)?;
+        self.set_int(offset, value, word_size);
+        Ok(())
+    }
+    /// Tries to set signed integer of `word_size <= 16` in slice of [`InSitu::swap_size()`] at
+    /// big-endian `offset` endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16`, [`Error::WordSwap`] if
+    /// the view is word-swapped and `word_size` is odd, and [`Error::OutOfBounds`] if the word
+    /// exceeds the slice.
+    fn try_set_int128(
+        &mut self,
+        offset: usize,
+        value: i128,
+        word_size: usize,
+    ) -> Result<(), Error> {
+        word(word_size, I128)?;
+        word_swap(word_size, self.is_word_swapped())?;
+        bounds(self.as_ref(), self.try_at(offset, word_size)?, word_size)?;
+        self.set_int128(offset, value, word_size);
+        Ok(())
+    }
+    /// Tries to set [`f32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set_f32(&mut self, offset: usize, value: f32) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, F32)?, F32)?;
+        self.set_f32(offset, value);
+        Ok(())
+    }
+    /// Tries to set [`f64`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set_f64(&mut self, offset: usize, value: f64) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, F64)?, F64)?;
+        self.set_f64(offset, value);
+        Ok(())
+    }
+    /// Sets `u24` as [`u32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently if the value fits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the value does not fit into the word, [`Error::WordSwap`] if
+    /// the view is word-swapped, and [`Error::OutOfBounds`] if the word exceeds the slice, in which
+    /// case nothing is set.
+    fn set_u24_checked(&mut self, offset: usize, value: u32) -> Result<(), Error> {
+        if u128::from(value) > umax(U24) {
+            return Err(Error::Overflow { word_size: U24 });
+        }
+        self.try_set_u24(offset, value)
+    }
+    /// Sets `u24` as [`u32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently saturating at the minimum or maximum of the word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`InSitu::is_word_swapped()`] since the word is of odd size.
+    #[allow(clippy::cast_possible_truncation)]
+    fn set_u24_saturating(&mut self, offset: usize, value: u32) {
+        let value = u128::from(value).min(umax(U24)) as u32;
+        self.set_u24(offset, value);
+    }
+    /// Sets `u24` as [`u32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently wrapping around at the boundaries of the word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`InSitu::is_word_swapped()`] since the word is of odd size.
+    #[allow(clippy::cast_possible_truncation)]
+    fn set_u24_wrapping(&mut self, offset: usize, value: u32) {
+        let value = (u128::from(value) & umax(U24)) as u32;
+        self.set_u24(offset, value);
+    }
+    /// Sets unsigned integer of `word_size <= 8` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently if the value fits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8`, [`Error::WordSwap`] if
+    /// the view is word-swapped and `word_size` is odd, [`Error::Overflow`] if the value does not
+    /// fit into the word, and [`Error::OutOfBounds`] if the word exceeds the slice, in which case
+    /// nothing is set.
+    fn set_uint_checked(
+        &mut self,
+        offset: usize,
+        value: u64,
+        word_size: usize,
+    ) -> Result<(), Error> {
+        word(word_size, U64)?;
+        if u128::from(value) > umax(word_size) {
+            return Err(Error::Overflow { word_size });
+        }
+        self.try_set_uint(offset, value, word_size)
+    }
+    /// Sets unsigned integer of `word_size <= 8` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently saturating at the minimum or maximum of the word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_size` is zero or exceeds `8` or if [`InSitu::is_word_swapped()`] and
+    /// `word_size` is odd and above two bytes.
+    #[allow(clippy::cast_possible_truncation)]
+    fn set_uint_saturating(&mut self, offset: usize, value: u64, word_size: usize) {
+        let word_size = sized(word_size, U64);
+        let value = u128::from(value).min(umax(word_size)) as u64;
+        self.set_uint(offset, value, word_size);
+    }
+    /// Sets unsigned integer of `word_size <= 8` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently wrapping around at the boundaries of the word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_size` is zero or exceeds `8` or if [`InSitu::is_word_swapped()`] and
+    /// `word_size` is odd and above two bytes.
+    #[allow(clippy::cast_possible_truncation)]
+    fn set_uint_wrapping(&mut self, offset: usize, value: u64, word_size: usize) {
+        let word_size = sized(word_size, U64);
+        let value = (u128::from(value) & umax(word_size)) as u64;
+        self.set_uint(offset, value, word_size);
+    }
+    /// Sets unsigned integer of `word_size <= 16` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently if the value fits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16`, [`Error::WordSwap`] if
+    /// the view is word-swapped and `word_size` is odd, [`Error::Overflow`] if the value does not
+    /// fit into the word, and [`Error::OutOfBounds`] if the word exceeds the slice, in which case
+    /// nothing is set.
+    fn set_uint128_checked(
+        &mut self,
+        offset: usize,
+        value: u128,
+        word_size: usize,
+    ) -> Result<(), Error> {
+        word(word_size, U128)?;
+        if value > umax(word_size) {
+            return Err(Error::Overflow { word_size });
+        }
+        self.try_set_uint128(offset, value, word_size)
+    }
+    /// Sets unsigned integer of `word_size <= 16` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently saturating at the minimum or maximum of the word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_size` is zero or exceeds `16` or if [`InSitu::is_word_swapped()`] and
+    /// `word_size` is odd and above two bytes.
+    fn set_uint128_saturating(&mut self, offset: usize, value: u128, word_size: usize) {
+        let word_size = sized(word_size, U128);
+        let value = value.min(umax(word_size));
+        self.set_uint128(offset, value, word_size);
+    }
+    /// Sets unsigned integer of `word_size <= 16` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently wrapping around at the boundaries of the word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_size` is zero or exceeds `16` or if [`InSitu::is_word_swapped()`] and
+    /// `word_size` is odd and above two bytes.
+    fn set_uint128_wrapping(&mut self, offset: usize, value: u128, word_size: usize) {
+        let word_size = sized(word_size, U128);
+        let value = value & umax(word_size);
+        self.set_uint128(offset, value, word_size);
+    }
+    /// Sets `i24` as [`i32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently if the value fits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::Overflow`] if the value does not fit into the word, [`Error::WordSwap`] if
+    /// the view is word-swapped, and [`Error::OutOfBounds`] if the word exceeds the slice, in which
+    /// case nothing is set.
+    fn set_i24_checked(&mut self, offset: usize, value: i32) -> Result<(), Error> {
+        if !(imin(I24)..=imax(I24)).contains(&i128::from(value)) {
+            return Err(Error::Overflow { word_size: I24 });
+        }
+        self.try_set_i24(offset, value)
+    }
+    /// Sets `i24` as [`i32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently saturating at the minimum or maximum of the word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`InSitu::is_word_swapped()`] since the word is of odd size.
+    #[allow(clippy::cast_possible_truncation)]
+    fn set_i24_saturating(&mut self, offset: usize, value: i32) {
+        let value = i128::from(value).clamp(imin(I24), imax(I24)) as i32;
+        self.set_i24(offset, value);
+    }
+    /// Sets `i24` as [`i32`] in slice of [`InSitu::swap_size()`] at big-endian `offset`
+    /// endian-independently wrapping around at the boundaries of the word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if [`InSitu::is_word_swapped()`] since the word is of odd size.
+    #[allow(clippy::cast_possible_truncation)]
+    fn set_i24_wrapping(&mut self, offset: usize, value: i32) {
+        let value = iwrap(i128::from(value), I24) as i32;
+        self.set_i24(offset, value);
+    }
+    /// Sets signed integer of `word_size <= 8` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently if the value fits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8`, [`Error::WordSwap`] if
+    /// the view is word-swapped and `word_size` is odd, [`Error::Overflow`] if the value does not
+    /// fit into the word, and [`Error::OutOfBounds`] if the word exceeds the slice, in which case
+    /// nothing is set.
+    fn set_int_checked(
+        &mut self,
+        offset: usize,
+        value: i64,
+        word_size: usize,
+    ) -> Result<(), Error> {
+        word(word_size, I64)?;
+        if !(imin(word_size)..=imax(word_size)).contains(&i128::from(value)) {
+            return Err(Error::Overflow { word_size });
+        }
+        self.try_set_int(offset, value, word_size)
+    }
+    /// Sets signed integer of `word_size <= 8` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently saturating at the minimum or maximum of the word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_size` is zero or exceeds `8` or if [`InSitu::is_word_swapped()`] and
+    /// `word_size` is odd and above two bytes.
+    #[allow(clippy::cast_possible_truncation)]
+    fn set_int_saturating(&mut self, offset: usize, value: i64, word_size: usize) {
+        let word_size = sized(word_size, I64);
+        let value = i128::from(value).clamp(imin(word_size), imax(word_size)) as i64;
+        self.set_int(offset, value, word_size);
+    }
+    /// Sets signed integer of `word_size <= 8` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently wrapping around at the boundaries of the word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_size` is zero or exceeds `8` or if [`InSitu::is_word_swapped()`] and
+    /// `word_size` is odd and above two bytes.
+    #[allow(clippy::cast_possible_truncation)]
+    fn set_int_wrapping(&mut self, offset: usize, value: i64, word_size: usize) {
+        let word_size = sized(word_size, I64);
+        let value = iwrap(i128::from(value), word_size) as i64;
+        self.set_int(offset, value, word_size);
+    }
+    /// Sets signed integer of `word_size <= 16` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently if the value fits.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16`, [`Error::WordSwap`] if
+    /// the view is word-swapped and `word_size` is odd, [`Error::Overflow`] if the value does not
+    /// fit into the word, and [`Error::OutOfBounds`] if the word exceeds the slice, in which case
+    /// nothing is set.
+    fn set_int128_checked(
+        &mut self,
+        offset: usize,
+        value: i128,
+        word_size: usize,
+    ) -> Result<(), Error> {
+        word(word_size, I128)?;
+        if !(imin(word_size)..=imax(word_size)).contains(&value) {
+            return Err(Error::Overflow { word_size });
         }
+        self.try_set_int128(offset, value, word_size)
+    }
+    /// Sets signed integer of `word_size <= 16` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently saturating at the minimum or maximum of the word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_size` is zero or exceeds `16` or if [`InSitu::is_word_swapped()`] and
+    /// `word_size` is odd and above two bytes.
+    fn set_int128_saturating(&mut self, offset: usize, value: i128, word_size: usize) {
+        let word_size = sized(word_size, I128);
+        let value = value.clamp(imin(word_size), imax(word_size));
+        self.set_int128(offset, value, word_size);
+    }
+    /// Sets signed integer of `word_size <= 16` in slice of [`InSitu::swap_size()`] at big-endian
+    /// `offset` endian-independently wrapping around at the boundaries of the word.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `word_size` is zero or exceeds `16` or if [`InSitu::is_word_swapped()`] and
+    /// `word_size` is odd and above two bytes.
+    fn set_int128_wrapping(&mut self, offset: usize, value: i128, word_size: usize) {
+        let word_size = sized(word_size, I128);
+        let value = iwrap(value, word_size);
+        self.set_int128(offset, value, word_size);
+    }
+    /// Updates internet checksum of the bytes in `range` at big-endian `offset` of the [`u16`]
+    /// checksum field.
+    ///
+    /// Zeros the field, computes the checksum via [`InSitu::internet_checksum()`], and sets it
+    /// endian-independently. Returns the checksum.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the field or `range` exceeds the slice.
+    fn update_checksum_u16(&mut self, offset: usize, range: Range<usize>) -> Result<u16, Error> {
+        self.update_checksum_u16_with(offset, range, &[])
+    }
+    /// Updates internet checksum of the bytes in `range` prepended with `pseudo_header` bytes at
+    /// big-endian `offset` of the [`u16`] checksum field.
+    ///
+    /// See [`Self::update_checksum_u16()`] and [`InSitu::internet_checksum_with()`].
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the field or `range` exceeds the slice.
+    fn update_checksum_u16_with(
+        &mut self,
+        offset: usize,
+        range: Range<usize>,
+        pseudo_header: &[u8],
+    ) -> Result<u16, Error> {
+        checksum::range(self.as_ref(), range.clone())?;
+        self.try_set_u16(offset, 0)?;
+        let checksum = self.internet_checksum_with(range, pseudo_header)?;
+        self.set_u16(offset, checksum);
+        Ok(checksum)
+    }
+    /// Sets [`u16`] at big-endian `offset` endian-independently and incrementally updates the
+    /// internet checksum at big-endian `checksum_offset` as in RFC 1624.
+    ///
+    /// The field must be at an even distance to the start of the checksummed range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the field or the checksum exceeds the slice, in which case
+    /// nothing is set.
+    fn set_u16_checksummed(
+        &mut self,
+        offset: usize,
+        value: u16,
+        checksum_offset: usize,
+    ) -> Result<(), Error> {
+        let old = self.try_u16(offset)?;
+        let checksum = self.try_u16(checksum_offset)?;
+        self.set_u16(offset, value);
+        let sum = u64::from(!checksum) + u64::from(!old) + u64::from(value);
+        self.set_u16(checksum_offset, !checksum::fold(sum));
+        Ok(())
+    }
+    /// Sets [`u32`] at big-endian `offset` endian-independently and incrementally updates the
+    /// internet checksum at big-endian `checksum_offset` as in RFC 1624, e.g., when rewriting IPv4
+    /// addresses.
+    ///
+    /// The field must be at an even distance to the start of the checksummed range.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the field or the checksum exceeds the slice, in which case
+    /// nothing is set.
+    fn set_u32_checksummed(
+        &mut self,
+        offset: usize,
+        value: u32,
+        checksum_offset: usize,
+    ) -> Result<(), Error> {
+        bounds(self.as_ref(), self.try_at(offset, U32)?, U32)?;
+        let old = [self.try_u16(offset)?, self.try_u16(offset + U16)?];
+        let checksum = self.try_u16(checksum_offset)?;
+        self.set_u32(offset, value);
+        let new = [self.u16(offset), self.u16(offset + U16)];
+        let sum = u64::from(!checksum)
+            + old.iter().map(|&word| u64::from(!word)).sum::<u64>()
+            + new.iter().copied().map(u64::from).sum::<u64>();
+        self.set_u16(checksum_offset, !checksum::fold(sum));
+        Ok(())
     }
 }
 
@@ -481,7 +3282,8 @@ pub trait InSituMut<Scope = ()>: InSitu<Scope> + AsMut<[u8]> {
 /// Abstracts immutable as well as mutable generic bytes view types like <code>&\[[u8]\]</code> and
 /// <code>&mut \[[u8]\]</code> as immutable views.
 ///
-/// With the `bytes` feature, abstacts `Bytes` and `BytesMut` as well.
+/// With the `alloc` feature, abstracts `Vec<u8>` as well. With the `bytes` feature, abstacts
+/// `Bytes` and `BytesMut` as well.
 ///
 /// Requires some standard nice-to-have but easily-to-get traits, so the wrapper can just derive
 /// them. Requires methods to be implemented to split views into subviews.
@@ -502,7 +3304,8 @@ pub trait Raw: AsRef<[u8]> + Default + PartialEq + Eq + PartialOrd + Ord + Debug
 
 /// Abstracts mutable generic bytes view types like <code>&mut \[[u8]\]</code> as mutable view.
 ///
-/// With the `bytes` feature, abstacts `BytesMut` as well.
+/// With the `alloc` feature, abstracts `Vec<u8>` as well. With the `bytes` feature, abstacts
+/// `BytesMut` as well.
 ///
 /// This trait is auto-implemented for <code>[Raw] + [AsMut]\<\[[u8]\]\></code> implementors
 /// extending the immutable views with mutable ones.
@@ -511,6 +3314,18 @@ pub trait RawMut: Raw + AsMut<[u8]> {}
 // Auto-implement [`RawMut`] for <code>[Raw] + [AsMut]\<\[[u8]\]\></code> implementors.
 impl<T: Raw + AsMut<[u8]>> RawMut for T {}
 
+/// Abstracts growable generic bytes types like `Vec<u8>` as growable view.
+///
+/// With the `bytes` feature, abstacts `BytesMut` as well.
+///
+/// Requires methods to be implemented to append bytes.
+pub trait RawGrow: RawMut {
+    /// Resizes the bytes to `new_len` by truncating or by appending bytes of `value`.
+    fn resize(&mut self, new_len: usize, value: u8);
+    /// Appends all bytes in `extend`.
+    fn extend_from_slice(&mut self, extend: &[u8]);
+}
+
 impl Raw for &[u8] {
     fn split_off(&mut self, at: usize) -> Self {
         let (l, r) = self.split_at(at);
@@ -539,6 +3354,28 @@ impl Raw for &mut [u8] {
     }
 }
 
+#[cfg(feature = "alloc")]
+impl Raw for alloc::vec::Vec<u8> {
+    fn split_off(&mut self, at: usize) -> Self {
+        self.split_off(at)
+    }
+    fn split_to(&mut self, at: usize) -> Self {
+        let mut r = self.split_off(at);
+        mem::swap(self, &mut r);
+        r
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl RawGrow for alloc::vec::Vec<u8> {
+    fn resize(&mut self, new_len: usize, value: u8) {
+        self.resize(new_len, value);
+    }
+    fn extend_from_slice(&mut self, extend: &[u8]) {
+        self.extend_from_slice(extend);
+    }
+}
+
 #[cfg(feature = "bytes")]
 impl Raw for bytes::Bytes {
     fn split_off(&mut self, at: usize) -> Self {
@@ -559,17 +3396,54 @@ impl Raw for bytes::BytesMut {
     }
 }
 
+#[cfg(feature = "bytes")]
+impl RawGrow for bytes::BytesMut {
+    fn resize(&mut self, new_len: usize, value: u8) {
+        self.resize(new_len, value);
+    }
+    fn extend_from_slice(&mut self, extend: &[u8]) {
+        self.extend_from_slice(extend);
+    }
+}
+
 /// Helper type describing the underlying byte order.
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///
+/// Detects the byte order from magic numbers via [`Self::detect_u16()`], [`Self::detect_u32()`],
+/// and the table-driven [`Self::detect()`].
+///
+/// Defaults to big-endian byte order, i.e., the network byte order.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Order {
     /// Big-endian byte order.
+    #[default]
     BE,
     /// Little-endian byte order.
     LE,
+    /// Mixed-endian byte order of big-endian 16-bit words in little-endian word order, e.g., the
+    /// bytes `CDAB` of the 32-bit word `ABCD`.
+    CDAB,
+    /// Mixed-endian byte order of little-endian 16-bit words in big-endian word order, e.g., the
+    /// bytes `BADC` of the 32-bit word `ABCD`.
+    BADC,
+}
+
+impl Order {
+    /// Whether the 16-bit words are in big-endian byte order, see [`InSitu::is_be()`].
+    #[must_use]
+    pub const fn is_be(self) -> bool {
+        matches!(self, Self::BE | Self::CDAB)
+    }
+    /// Whether the 16-bit words are in reverse word order, see [`InSitu::is_word_swapped()`].
+    #[must_use]
+    pub const fn is_word_swapped(self) -> bool {
+        matches!(self, Self::CDAB | Self::BADC)
+    }
 }
 
 /// Helper type specifying whether to take the bytes of the header only or the whole packet.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Take {
     /// Take bytes of header only.
     Header,
@@ -0,0 +1,81 @@
+use super::{Error, InSitu, InSituMut, Order, Raw, RawMut, error::swap};
+
+/// Wraps [`Raw`] bytes of runtime [`Order`] and swap size.
+///
+/// Provides the boilerplate of a wrapper type storing the bytes and how to access them. It can be
+/// used directly via <code>[InSitu]\<()\></code> or newtyped, in which case the newtype might
+/// implement <code>[InSitu]\<Scope\></code> for a private type `Scope` by delegating to the
+/// [`Packet`] to keep the accessors private.
+///
+/// It implements [`Raw`] itself, so splitting it preserves the [`Order`] and swap size on both
+/// halves.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Packet<R: Raw> {
+    raw: R,
+    order: Order,
+    swap_size: usize,
+}
+
+impl<R: Raw> Packet<R> {
+    /// Wraps `raw` bytes of `order` and `swap_size`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::SwapSize`] if `swap_size` is neither zero nor a power of two.
+    pub fn new(raw: R, order: Order, swap_size: usize) -> Result<Self, Error> {
+        swap(swap_size)?;
+        Ok(Self {
+            raw,
+            order,
+            swap_size,
+        })
+    }
+    /// Unwraps the raw bytes.
+    pub fn into_raw(self) -> R {
+        self.raw
+    }
+}
+
+impl<R: Raw> AsRef<[u8]> for Packet<R> {
+    fn as_ref(&self) -> &[u8] {
+        self.raw.as_ref()
+    }
+}
+
+impl<R: RawMut> AsMut<[u8]> for Packet<R> {
+    fn as_mut(&mut self) -> &mut [u8] {
+        self.raw.as_mut()
+    }
+}
+
+impl<R: Raw> Raw for Packet<R> {
+    fn split_off(&mut self, at: usize) -> Self {
+        Self {
+            raw: self.raw.split_off(at),
+            ..*self
+        }
+    }
+    fn split_to(&mut self, at: usize) -> Self {
+        Self {
+            raw: self.raw.split_to(at),
+            ..*self
+        }
+    }
+}
+
+impl<R: Raw> InSitu for Packet<R> {
+    fn swap_size(&self) -> usize {
+        self.swap_size
+    }
+    fn is_be(&self) -> bool {
+        self.order.is_be()
+    }
+    fn is_word_swapped(&self) -> bool {
+        self.order.is_word_swapped()
+    }
+    fn order(&self) -> Order {
+        self.order
+    }
+}
+
+impl<R: RawMut> InSituMut for Packet<R> {}
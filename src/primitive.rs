@@ -0,0 +1,345 @@
+use super::{Error, F32, F64, I8, I16, I32, I64, I128, InSitu, InSituMut, U8, U16, U32, U64, U128};
+
+/// Primitive types accessible via [`InSitu::get()`] and [`InSituMut::set()`].
+///
+/// This trait is sealed and implemented for [`bool`], [`u8`], [`u16`], [`u32`], [`u64`], [`u128`],
+/// [`i8`], [`i16`], [`i32`], [`i64`], [`i128`], [`f32`], and [`f64`]. It allows generic code over
+/// element types by forwarding to the concrete methods like [`InSitu::u16()`].
+pub trait InSituPrimitive: sealed::Sealed + Copy {
+    /// Size of the type in bytes.
+    const SIZE: usize;
+    /// Gets value in slice of [`InSitu::swap_size()`] at big-endian `offset` of `view`
+    /// endian-independently.
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self;
+    /// Tries to get value in slice of [`InSitu::swap_size()`] at big-endian `offset` of `view`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error>;
+    /// Sets `value` in slice of [`InSitu::swap_size()`] at big-endian `offset` of `view`
+    /// endian-independently.
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self);
+    /// Tries to set `value` in slice of [`InSitu::swap_size()`] at big-endian `offset` of `view`
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the slice.
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error>;
+}
+
+/// Primitive types castable from any bytes via `InSitu::cast_slice()`.
+///
+/// This trait is sealed and implemented for all [`InSituPrimitive`] types except [`bool`] as any
+/// bit pattern of them is valid.
+pub trait InSituCast: InSituPrimitive {}
+
+mod sealed {
+    pub trait Sealed {}
+
+    impl Sealed for bool {}
+    impl Sealed for u8 {}
+    impl Sealed for u16 {}
+    impl Sealed for u32 {}
+    impl Sealed for u64 {}
+    impl Sealed for u128 {}
+    impl Sealed for i8 {}
+    impl Sealed for i16 {}
+    impl Sealed for i32 {}
+    impl Sealed for i64 {}
+    impl Sealed for i128 {}
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+impl InSituCast for u8 {}
+impl InSituCast for u16 {}
+impl InSituCast for u32 {}
+impl InSituCast for u64 {}
+impl InSituCast for u128 {}
+impl InSituCast for i8 {}
+impl InSituCast for i16 {}
+impl InSituCast for i32 {}
+impl InSituCast for i64 {}
+impl InSituCast for i128 {}
+impl InSituCast for f32 {}
+impl InSituCast for f64 {}
+
+impl InSituPrimitive for bool {
+    const SIZE: usize = U8;
+
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self {
+        view.bool(offset)
+    }
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error> {
+        view.try_bool(offset)
+    }
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self) {
+        view.set_bool(offset, value);
+    }
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error> {
+        view.try_set_bool(offset, value)
+    }
+}
+
+impl InSituPrimitive for u8 {
+    const SIZE: usize = U8;
+
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self {
+        view.u8(offset)
+    }
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error> {
+        view.try_u8(offset)
+    }
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self) {
+        view.set_u8(offset, value);
+    }
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error> {
+        view.try_set_u8(offset, value)
+    }
+}
+
+impl InSituPrimitive for u16 {
+    const SIZE: usize = U16;
+
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self {
+        view.u16(offset)
+    }
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error> {
+        view.try_u16(offset)
+    }
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self) {
+        view.set_u16(offset, value);
+    }
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error> {
+        view.try_set_u16(offset, value)
+    }
+}
+
+impl InSituPrimitive for u32 {
+    const SIZE: usize = U32;
+
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self {
+        view.u32(offset)
+    }
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error> {
+        view.try_u32(offset)
+    }
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self) {
+        view.set_u32(offset, value);
+    }
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error> {
+        view.try_set_u32(offset, value)
+    }
+}
+
+impl InSituPrimitive for u64 {
+    const SIZE: usize = U64;
+
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self {
+        view.u64(offset)
+    }
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error> {
+        view.try_u64(offset)
+    }
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self) {
+        view.set_u64(offset, value);
+    }
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error> {
+        view.try_set_u64(offset, value)
+    }
+}
+
+impl InSituPrimitive for u128 {
+    const SIZE: usize = U128;
+
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self {
+        view.u128(offset)
+    }
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error> {
+        view.try_u128(offset)
+    }
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self) {
+        view.set_u128(offset, value);
+    }
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error> {
+        view.try_set_u128(offset, value)
+    }
+}
+
+impl InSituPrimitive for i8 {
+    const SIZE: usize = I8;
+
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self {
+        view.i8(offset)
+    }
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error> {
+        view.try_i8(offset)
+    }
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self) {
+        view.set_i8(offset, value);
+    }
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error> {
+        view.try_set_i8(offset, value)
+    }
+}
+
+impl InSituPrimitive for i16 {
+    const SIZE: usize = I16;
+
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self {
+        view.i16(offset)
+    }
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error> {
+        view.try_i16(offset)
+    }
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self) {
+        view.set_i16(offset, value);
+    }
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error> {
+        view.try_set_i16(offset, value)
+    }
+}
+
+impl InSituPrimitive for i32 {
+    const SIZE: usize = I32;
+
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self {
+        view.i32(offset)
+    }
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error> {
+        view.try_i32(offset)
+    }
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self) {
+        view.set_i32(offset, value);
+    }
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error> {
+        view.try_set_i32(offset, value)
+    }
+}
+
+impl InSituPrimitive for i64 {
+    const SIZE: usize = I64;
+
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self {
+        view.i64(offset)
+    }
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error> {
+        view.try_i64(offset)
+    }
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self) {
+        view.set_i64(offset, value);
+    }
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error> {
+        view.try_set_i64(offset, value)
+    }
+}
+
+impl InSituPrimitive for i128 {
+    const SIZE: usize = I128;
+
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self {
+        view.i128(offset)
+    }
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error> {
+        view.try_i128(offset)
+    }
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self) {
+        view.set_i128(offset, value);
+    }
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error> {
+        view.try_set_i128(offset, value)
+    }
+}
+
+impl InSituPrimitive for f32 {
+    const SIZE: usize = F32;
+
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self {
+        view.f32(offset)
+    }
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error> {
+        view.try_f32(offset)
+    }
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self) {
+        view.set_f32(offset, value);
+    }
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error> {
+        view.try_set_f32(offset, value)
+    }
+}
+
+impl InSituPrimitive for f64 {
+    const SIZE: usize = F64;
+
+    fn get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Self {
+        view.f64(offset)
+    }
+    fn try_get<Scope, V: InSitu<Scope> + ?Sized>(view: &V, offset: usize) -> Result<Self, Error> {
+        view.try_f64(offset)
+    }
+    fn set<Scope, V: InSituMut<Scope> + ?Sized>(view: &mut V, offset: usize, value: Self) {
+        view.set_f64(offset, value);
+    }
+    fn try_set<Scope, V: InSituMut<Scope> + ?Sized>(
+        view: &mut V,
+        offset: usize,
+        value: Self,
+    ) -> Result<(), Error> {
+        view.try_set_f64(offset, value)
+    }
+}
@@ -0,0 +1,48 @@
+use super::{Error, error::bounds};
+
+/// Gets length of string at `offset` up to its NUL terminator or of `max` bytes if unterminated.
+pub fn terminated(bytes: &[u8], offset: usize, max: usize) -> Result<usize, Error> {
+    bounds(bytes, offset, 0)?;
+    let tail = &bytes[offset..];
+    let window = &tail[..max.min(tail.len())];
+    match window.iter().position(|&byte| byte == 0) {
+        Some(length) => Ok(length),
+        None if window.len() == max => Ok(max),
+        None => Err(Error::OutOfBounds {
+            offset,
+            word_size: max,
+            length: bytes.len(),
+        }),
+    }
+}
+
+/// Gets length of string of `width` bytes at `offset` without its trailing `pad` bytes.
+pub fn trimmed(bytes: &[u8], offset: usize, width: usize, pad: u8) -> Result<usize, Error> {
+    bounds(bytes, offset, width)?;
+    let length = bytes[offset..][..width]
+        .iter()
+        .rposition(|&byte| byte != pad)
+        .map_or(0, |last| last + 1);
+    Ok(length)
+}
+
+/// Gets longest prefix of `value` of at most `length` bytes ending at a character boundary.
+pub fn truncated(value: &str, length: usize) -> &str {
+    let mut length = length.min(value.len());
+    while !value.is_char_boundary(length) {
+        length -= 1;
+    }
+    &value[..length]
+}
+
+/// Gets maximum length representable by a length prefix of `prefix_size` bytes.
+pub fn prefixed(prefix_size: usize) -> usize {
+    usize::try_from(u64::MAX >> ((8 - prefix_size) * 8)).unwrap_or(usize::MAX)
+}
+
+/// Copies `value` into `dst` and fills the remaining bytes with `pad`.
+pub fn padded(dst: &mut [u8], value: &[u8], pad: u8) {
+    let (head, tail) = dst.split_at_mut(value.len());
+    head.copy_from_slice(value);
+    tail.fill(pad);
+}
@@ -0,0 +1,578 @@
+use super::{
+    Dyn, Error, F32, F64, I16, I24, I32, I64, I128, InSitu, InSituMut, Order, U8, U16, U24, U32,
+    U64, U128,
+    error::{within, word, word_swap},
+};
+use byteorder::{ByteOrder, NativeEndian};
+use core::marker::PhantomData;
+
+/// Wraps volatile bytes at a raw pointer in byte order selected at runtime, e.g., memory-mapped
+/// device registers.
+///
+/// Gets and sets 8-bit to 128-bit integers and floats at big-endian offsets like the `try_`
+/// accessors of [`InSitu`] and [`InSituMut`], but via [`read_volatile()`] and [`write_volatile()`]
+/// of their full word width, so an aligned 32-bit register is accessed at once and never byte by
+/// byte. The words must be aligned to their size, otherwise the accessors return
+/// [`Error::Misaligned`]. Only words whose sizes are no power of two like `u24` are accessed byte
+/// by byte without alignment requirement.
+///
+/// It does not implement [`InSitu`] since that would hand out byte slices whose accesses the
+/// compiler may elide or merge. Instead, `#[derive(InSitu)]` generates a register block wrapper
+/// over it via `#[in_situ(volatile = Name)]` and `Layout::evaluate_volatile()` evaluates runtime
+/// layouts against it.
+///
+/// [`read_volatile()`]: core::ptr::read_volatile
+/// [`write_volatile()`]: core::ptr::write_volatile
+#[derive(Debug)]
+pub struct Volatile<'a> {
+    ptr: *mut u8,
+    len: usize,
+    order: Order,
+    bytes: PhantomData<&'a mut [u8]>,
+}
+
+impl<'a> Volatile<'a> {
+    /// Wraps `len` volatile bytes at `ptr` of `order`.
+    ///
+    /// # Safety
+    ///
+    /// The `ptr` must be non-null and valid for reads and writes of `len` bytes for lifetime `'a`,
+    /// during which the bytes must not be accessed other than via the returned wrapper.
+    pub const unsafe fn new(ptr: *mut u8, len: usize, order: Order) -> Self {
+        Self {
+            ptr,
+            len,
+            order,
+            bytes: PhantomData,
+        }
+    }
+    /// Wraps mutably borrowed `bytes` of `order`, e.g., for testing against a heap allocation.
+    pub const fn from_slice(bytes: &'a mut [u8], order: Order) -> Self {
+        // SAFETY: The bytes are valid and exclusively borrowed for lifetime `'a`.
+        unsafe { Self::new(bytes.as_mut_ptr(), bytes.len(), order) }
+    }
+    /// Length in bytes.
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+    /// Tests if the length is zero.
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// Byte order.
+    #[must_use]
+    pub const fn order(&self) -> Order {
+        self.order
+    }
+    /// Tries to get aligned pointer to word of `T` at `offset`.
+    fn word<T>(&self, offset: usize) -> Result<*mut T, Error> {
+        within(self.len, offset, size_of::<T>())?;
+        let word = self.ptr.wrapping_add(offset).cast::<T>();
+        if word.is_aligned() {
+            Ok(word)
+        } else {
+            Err(Error::Misaligned {
+                offset,
+                align: align_of::<T>(),
+            })
+        }
+    }
+    /// Reads native word of `T` at `offset` volatilely.
+    fn read<T>(&self, offset: usize) -> Result<T, Error> {
+        let word = self.word::<T>(offset)?;
+        // SAFETY: The word is within the bytes valid for reads and aligned.
+        Ok(unsafe { word.read_volatile() })
+    }
+    /// Writes native word `raw` of `T` at `offset` volatilely.
+    #[allow(clippy::needless_pass_by_ref_mut)]
+    fn write<T>(&mut self, offset: usize, raw: T) -> Result<(), Error> {
+        let word = self.word::<T>(offset)?;
+        // SAFETY: The word is within the bytes valid for writes, aligned, and exclusively borrowed.
+        unsafe { word.write_volatile(raw) };
+        Ok(())
+    }
+    /// Reads `word_size <= 16` bytes at `offset` volatilely into the head of the returned bytes.
+    ///
+    /// Words of power-of-two sizes are read at once, others byte by byte.
+    fn load(&self, offset: usize, word_size: usize) -> Result<[u8; U128], Error> {
+        word_swap(word_size, self.order.is_word_swapped())?;
+        let mut raw = [0; U128];
+        match word_size {
+            U8 => raw[0] = self.read(offset)?,
+            U16 => NativeEndian::write_u16(&mut raw, self.read(offset)?),
+            U32 => NativeEndian::write_u32(&mut raw, self.read(offset)?),
+            U64 => NativeEndian::write_u64(&mut raw, self.read(offset)?),
+            U128 => NativeEndian::write_u128(&mut raw, self.read(offset)?),
+            _ => {
+                within(self.len, offset, word_size)?;
+                for (index, byte) in raw[..word_size].iter_mut().enumerate() {
+                    *byte = self.read(offset + index)?;
+                }
+            }
+        }
+        Ok(raw)
+    }
+    /// Writes `raw` bytes of `raw.len() <= 16` at `offset` volatilely.
+    ///
+    /// Words of power-of-two sizes are written at once, others byte by byte.
+    fn store(&mut self, offset: usize, raw: &[u8]) -> Result<(), Error> {
+        match raw.len() {
+            U8 => self.write(offset, raw[0]),
+            U16 => self.write(offset, NativeEndian::read_u16(raw)),
+            U32 => self.write(offset, NativeEndian::read_u32(raw)),
+            U64 => self.write(offset, NativeEndian::read_u64(raw)),
+            U128 => self.write(offset, NativeEndian::read_u128(raw)),
+            word_size => {
+                within(self.len, offset, word_size)?;
+                for (index, &byte) in raw.iter().enumerate() {
+                    self.write(offset + index, byte)?;
+                }
+                Ok(())
+            }
+        }
+    }
+    /// Tries to get [`bool`] at big-endian `offset` volatilely.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes.
+    pub fn try_bool(&self, offset: usize) -> Result<bool, Error> {
+        Ok(self.try_u8(offset)? != 0)
+    }
+    /// Tries to get [`u8`] at big-endian `offset` volatilely.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes.
+    pub fn try_u8(&self, offset: usize) -> Result<u8, Error> {
+        self.read(offset)
+    }
+    /// Tries to get [`u16`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned.
+    pub fn try_u16(&self, offset: usize) -> Result<u16, Error> {
+        let raw = self.read::<u16>(offset)?.to_ne_bytes();
+        Ok(Dyn::<_>::new(&raw[..], self.order).u16(0))
+    }
+    /// Tries to get `u24` as [`u32`] at big-endian `offset` volatilely byte by byte and
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSwap`] if the order is word-swapped and [`Error::OutOfBounds`] if the
+    /// word exceeds the bytes.
+    pub fn try_u24(&self, offset: usize) -> Result<u32, Error> {
+        let raw = self.load(offset, U24)?;
+        Ok(Dyn::<_>::new(&raw[..U24], self.order).u24(0))
+    }
+    /// Tries to get [`u32`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned.
+    pub fn try_u32(&self, offset: usize) -> Result<u32, Error> {
+        let raw = self.read::<u32>(offset)?.to_ne_bytes();
+        Ok(Dyn::<_>::new(&raw[..], self.order).u32(0))
+    }
+    /// Tries to get [`u64`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned.
+    pub fn try_u64(&self, offset: usize) -> Result<u64, Error> {
+        let raw = self.read::<u64>(offset)?.to_ne_bytes();
+        Ok(Dyn::<_>::new(&raw[..], self.order).u64(0))
+    }
+    /// Tries to get [`u128`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned.
+    pub fn try_u128(&self, offset: usize) -> Result<u128, Error> {
+        let raw = self.read::<u128>(offset)?.to_ne_bytes();
+        Ok(Dyn::<_>::new(&raw[..], self.order).u128(0))
+    }
+    /// Tries to get unsigned integer of `word_size <= 8` at big-endian `offset` volatilely and
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8`, [`Error::WordSwap`] if
+    /// the order is word-swapped and `word_size` is odd, [`Error::OutOfBounds`] if the word exceeds
+    /// the bytes, and [`Error::Misaligned`] if the word of a power-of-two size is not aligned.
+    pub fn try_uint(&self, offset: usize, word_size: usize) -> Result<u64, Error> {
+        word(word_size, U64)?;
+        let raw = self.load(offset, word_size)?;
+        Ok(Dyn::<_>::new(&raw[..word_size], self.order).uint(0, word_size))
+    }
+    /// Tries to get unsigned integer of `word_size <= 16` at big-endian `offset` volatilely and
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16`, [`Error::WordSwap`] if
+    /// the order is word-swapped and `word_size` is odd, [`Error::OutOfBounds`] if the word exceeds
+    /// the bytes, and [`Error::Misaligned`] if the word of a power-of-two size is not aligned.
+    pub fn try_uint128(&self, offset: usize, word_size: usize) -> Result<u128, Error> {
+        word(word_size, U128)?;
+        let raw = self.load(offset, word_size)?;
+        Ok(Dyn::<_>::new(&raw[..word_size], self.order).uint128(0, word_size))
+    }
+    /// Tries to get [`i8`] at big-endian `offset` volatilely.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes.
+    pub fn try_i8(&self, offset: usize) -> Result<i8, Error> {
+        self.read(offset)
+    }
+    /// Tries to get [`i16`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned.
+    pub fn try_i16(&self, offset: usize) -> Result<i16, Error> {
+        let raw = self.read::<i16>(offset)?.to_ne_bytes();
+        Ok(Dyn::<_>::new(&raw[..], self.order).i16(0))
+    }
+    /// Tries to get `i24` as [`i32`] at big-endian `offset` volatilely byte by byte and
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSwap`] if the order is word-swapped and [`Error::OutOfBounds`] if the
+    /// word exceeds the bytes.
+    pub fn try_i24(&self, offset: usize) -> Result<i32, Error> {
+        let raw = self.load(offset, I24)?;
+        Ok(Dyn::<_>::new(&raw[..I24], self.order).i24(0))
+    }
+    /// Tries to get [`i32`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned.
+    pub fn try_i32(&self, offset: usize) -> Result<i32, Error> {
+        let raw = self.read::<i32>(offset)?.to_ne_bytes();
+        Ok(Dyn::<_>::new(&raw[..], self.order).i32(0))
+    }
+    /// Tries to get [`i64`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned.
+    pub fn try_i64(&self, offset: usize) -> Result<i64, Error> {
+        let raw = self.read::<i64>(offset)?.to_ne_bytes();
+        Ok(Dyn::<_>::new(&raw[..], self.order).i64(0))
+    }
+    /// Tries to get [`i128`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned.
+    pub fn try_i128(&self, offset: usize) -> Result<i128, Error> {
+        let raw = self.read::<i128>(offset)?.to_ne_bytes();
+        Ok(Dyn::<_>::new(&raw[..], self.order).i128(0))
+    }
+    /// Tries to get signed integer of `word_size <= 8` at big-endian `offset` volatilely and
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8`, [`Error::WordSwap`] if
+    /// the order is word-swapped and `word_size` is odd, [`Error::OutOfBounds`] if the word exceeds
+    /// the bytes, and [`Error::Misaligned`] if the word of a power-of-two size is not aligned.
+    pub fn try_int(&self, offset: usize, word_size: usize) -> Result<i64, Error> {
+        word(word_size, I64)?;
+        let raw = self.load(offset, word_size)?;
+        Ok(Dyn::<_>::new(&raw[..word_size], self.order).int(0, word_size))
+    }
+    /// Tries to get signed integer of `word_size <= 16` at big-endian `offset` volatilely and
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16`, [`Error::WordSwap`] if
+    /// the order is word-swapped and `word_size` is odd, [`Error::OutOfBounds`] if the word exceeds
+    /// the bytes, and [`Error::Misaligned`] if the word of a power-of-two size is not aligned.
+    pub fn try_int128(&self, offset: usize, word_size: usize) -> Result<i128, Error> {
+        word(word_size, I128)?;
+        let raw = self.load(offset, word_size)?;
+        Ok(Dyn::<_>::new(&raw[..word_size], self.order).int128(0, word_size))
+    }
+    /// Tries to get [`f32`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned.
+    pub fn try_f32(&self, offset: usize) -> Result<f32, Error> {
+        let raw = self.read::<u32>(offset)?.to_ne_bytes();
+        Ok(Dyn::<_>::new(&raw[..], self.order).f32(0))
+    }
+    /// Tries to get [`f64`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned.
+    pub fn try_f64(&self, offset: usize) -> Result<f64, Error> {
+        let raw = self.read::<u64>(offset)?.to_ne_bytes();
+        Ok(Dyn::<_>::new(&raw[..], self.order).f64(0))
+    }
+    /// Tries to set [`bool`] at big-endian `offset` volatilely.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes.
+    pub fn try_set_bool(&mut self, offset: usize, value: bool) -> Result<(), Error> {
+        self.try_set_u8(offset, value.into())
+    }
+    /// Tries to set [`u8`] at big-endian `offset` volatilely.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes.
+    pub fn try_set_u8(&mut self, offset: usize, value: u8) -> Result<(), Error> {
+        self.write(offset, value)
+    }
+    /// Tries to set [`u16`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned, in which case nothing is set.
+    pub fn try_set_u16(&mut self, offset: usize, value: u16) -> Result<(), Error> {
+        let mut raw = [0; U16];
+        Dyn::<_>::new(&mut raw[..], self.order).set_u16(0, value);
+        self.write(offset, u16::from_ne_bytes(raw))
+    }
+    /// Tries to set `u24` as [`u32`] at big-endian `offset` volatilely byte by byte and
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSwap`] if the order is word-swapped and [`Error::OutOfBounds`] if the
+    /// word exceeds the bytes, in which case nothing is set.
+    pub fn try_set_u24(&mut self, offset: usize, value: u32) -> Result<(), Error> {
+        word_swap(U24, self.order.is_word_swapped())?;
+        let mut raw = [0; U24];
+        Dyn::<_>::new(&mut raw[..], self.order).set_u24(0, value);
+        self.store(offset, &raw)
+    }
+    /// Tries to set [`u32`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned, in which case nothing is set.
+    pub fn try_set_u32(&mut self, offset: usize, value: u32) -> Result<(), Error> {
+        let mut raw = [0; U32];
+        Dyn::<_>::new(&mut raw[..], self.order).set_u32(0, value);
+        self.write(offset, u32::from_ne_bytes(raw))
+    }
+    /// Tries to set [`u64`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned, in which case nothing is set.
+    pub fn try_set_u64(&mut self, offset: usize, value: u64) -> Result<(), Error> {
+        let mut raw = [0; U64];
+        Dyn::<_>::new(&mut raw[..], self.order).set_u64(0, value);
+        self.write(offset, u64::from_ne_bytes(raw))
+    }
+    /// Tries to set [`u128`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned, in which case nothing is set.
+    pub fn try_set_u128(&mut self, offset: usize, value: u128) -> Result<(), Error> {
+        let mut raw = [0; U128];
+        Dyn::<_>::new(&mut raw[..], self.order).set_u128(0, value);
+        self.write(offset, u128::from_ne_bytes(raw))
+    }
+    /// Tries to set unsigned integer of `word_size <= 8` at big-endian `offset` volatilely and
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8`, [`Error::WordSwap`] if
+    /// the order is word-swapped and `word_size` is odd, [`Error::OutOfBounds`] if the word exceeds
+    /// the bytes, and [`Error::Misaligned`] if the word of a power-of-two size is not aligned, in
+    /// which case nothing is set.
+    pub fn try_set_uint(
+        &mut self,
+        offset: usize,
+        value: u64,
+        word_size: usize,
+    ) -> Result<(), Error> {
+        word(word_size, U64)?;
+        word_swap(word_size, self.order.is_word_swapped())?;
+        let mut raw = [0; U64];
+        Dyn::<_>::new(&mut raw[..word_size], self.order).set_uint(0, value, word_size);
+        self.store(offset, &raw[..word_size])
+    }
+    /// Tries to set unsigned integer of `word_size <= 16` at big-endian `offset` volatilely and
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16`, [`Error::WordSwap`] if
+    /// the order is word-swapped and `word_size` is odd, [`Error::OutOfBounds`] if the word exceeds
+    /// the bytes, and [`Error::Misaligned`] if the word of a power-of-two size is not aligned, in
+    /// which case nothing is set.
+    pub fn try_set_uint128(
+        &mut self,
+        offset: usize,
+        value: u128,
+        word_size: usize,
+    ) -> Result<(), Error> {
+        word(word_size, U128)?;
+        word_swap(word_size, self.order.is_word_swapped())?;
+        let mut raw = [0; U128];
+        Dyn::<_>::new(&mut raw[..word_size], self.order).set_uint128(0, value, word_size);
+        self.store(offset, &raw[..word_size])
+    }
+    /// Tries to set [`i8`] at big-endian `offset` volatilely.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes.
+    pub fn try_set_i8(&mut self, offset: usize, value: i8) -> Result<(), Error> {
+        self.write(offset, value)
+    }
+    /// Tries to set [`i16`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned, in which case nothing is set.
+    pub fn try_set_i16(&mut self, offset: usize, value: i16) -> Result<(), Error> {
+        let mut raw = [0; I16];
+        Dyn::<_>::new(&mut raw[..], self.order).set_i16(0, value);
+        self.write(offset, i16::from_ne_bytes(raw))
+    }
+    /// Tries to set `i24` as [`i32`] at big-endian `offset` volatilely byte by byte and
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSwap`] if the order is word-swapped and [`Error::OutOfBounds`] if the
+    /// word exceeds the bytes, in which case nothing is set.
+    pub fn try_set_i24(&mut self, offset: usize, value: i32) -> Result<(), Error> {
+        word_swap(I24, self.order.is_word_swapped())?;
+        let mut raw = [0; I24];
+        Dyn::<_>::new(&mut raw[..], self.order).set_i24(0, value);
+        self.store(offset, &raw)
+    }
+    /// Tries to set [`i32`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned, in which case nothing is set.
+    pub fn try_set_i32(&mut self, offset: usize, value: i32) -> Result<(), Error> {
+        let mut raw = [0; I32];
+        Dyn::<_>::new(&mut raw[..], self.order).set_i32(0, value);
+        self.write(offset, i32::from_ne_bytes(raw))
+    }
+    /// Tries to set [`i64`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned, in which case nothing is set.
+    pub fn try_set_i64(&mut self, offset: usize, value: i64) -> Result<(), Error> {
+        let mut raw = [0; I64];
+        Dyn::<_>::new(&mut raw[..], self.order).set_i64(0, value);
+        self.write(offset, i64::from_ne_bytes(raw))
+    }
+    /// Tries to set [`i128`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned, in which case nothing is set.
+    pub fn try_set_i128(&mut self, offset: usize, value: i128) -> Result<(), Error> {
+        let mut raw = [0; I128];
+        Dyn::<_>::new(&mut raw[..], self.order).set_i128(0, value);
+        self.write(offset, i128::from_ne_bytes(raw))
+    }
+    /// Tries to set signed integer of `word_size <= 8` at big-endian `offset` volatilely and
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `8`, [`Error::WordSwap`] if
+    /// the order is word-swapped and `word_size` is odd, [`Error::OutOfBounds`] if the word exceeds
+    /// the bytes, and [`Error::Misaligned`] if the word of a power-of-two size is not aligned, in
+    /// which case nothing is set.
+    pub fn try_set_int(
+        &mut self,
+        offset: usize,
+        value: i64,
+        word_size: usize,
+    ) -> Result<(), Error> {
+        word(word_size, I64)?;
+        word_swap(word_size, self.order.is_word_swapped())?;
+        let mut raw = [0; I64];
+        Dyn::<_>::new(&mut raw[..word_size], self.order).set_int(0, value, word_size);
+        self.store(offset, &raw[..word_size])
+    }
+    /// Tries to set signed integer of `word_size <= 16` at big-endian `offset` volatilely and
+    /// endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::WordSize`] if `word_size` is zero or exceeds `16`, [`Error::WordSwap`] if
+    /// the order is word-swapped and `word_size` is odd, [`Error::OutOfBounds`] if the word exceeds
+    /// the bytes, and [`Error::Misaligned`] if the word of a power-of-two size is not aligned, in
+    /// which case nothing is set.
+    pub fn try_set_int128(
+        &mut self,
+        offset: usize,
+        value: i128,
+        word_size: usize,
+    ) -> Result<(), Error> {
+        word(word_size, I128)?;
+        word_swap(word_size, self.order.is_word_swapped())?;
+        let mut raw = [0; I128];
+        Dyn::<_>::new(&mut raw[..word_size], self.order).set_int128(0, value, word_size);
+        self.store(offset, &raw[..word_size])
+    }
+    /// Tries to set [`f32`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned, in which case nothing is set.
+    pub fn try_set_f32(&mut self, offset: usize, value: f32) -> Result<(), Error> {
+        let mut raw = [0; F32];
+        Dyn::<_>::new(&mut raw[..], self.order).set_f32(0, value);
+        self.write(offset, u32::from_ne_bytes(raw))
+    }
+    /// Tries to set [`f64`] at big-endian `offset` volatilely and endian-independently.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::OutOfBounds`] if the word exceeds the bytes and [`Error::Misaligned`] if
+    /// the word is not aligned, in which case nothing is set.
+    pub fn try_set_f64(&mut self, offset: usize, value: f64) -> Result<(), Error> {
+        let mut raw = [0; F64];
+        Dyn::<_>::new(&mut raw[..], self.order).set_f64(0, value);
+        self.write(offset, u64::from_ne_bytes(raw))
+    }
+}
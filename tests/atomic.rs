@@ -0,0 +1,181 @@
+//! Tests of [`Shared`] atomic access between threads.
+#![cfg(all(target_has_atomic = "32", target_has_atomic = "64"))]
+
+use core::sync::atomic::Ordering;
+use in_situ::{Dyn, Error, InSitu, Order, Shared};
+use std::thread;
+
+/// Byte orders to test.
+const ORDERS: [Order; 4] = [Order::BE, Order::LE, Order::CDAB, Order::BADC];
+
+/// Number of threads.
+const THREADS: u32 = 4;
+
+/// Number of additions per thread.
+const ADDS: u32 = 1000;
+
+#[test]
+fn fetch_add() {
+    for order in ORDERS {
+        let mut words = [0_u64; 2];
+        // SAFETY: The words are valid for reads and writes of 16 bytes, aligned, and accessed via
+        // `shared` only with consistent sizes.
+        let shared = unsafe { Shared::new(words.as_mut_ptr().cast(), 16, order) };
+        thread::scope(|scope| {
+            for _ in 0..THREADS {
+                scope.spawn(|| {
+                    for _ in 0..ADDS {
+                        shared
+                            .atomic_fetch_add_u32(4, 1, Ordering::Relaxed)
+                            .unwrap();
+                        shared
+                            .atomic_fetch_add_u64(8, 0x1_0000_0001, Ordering::Relaxed)
+                            .unwrap();
+                    }
+                });
+            }
+        });
+        let total = THREADS * ADDS;
+        assert_eq!(shared.atomic_load_u32(4, Ordering::Relaxed), Ok(total));
+        assert_eq!(
+            shared.atomic_load_u64(8, Ordering::Relaxed),
+            Ok(u64::from(total) * 0x1_0000_0001)
+        );
+        assert_eq!(shared.atomic_load_u32(0, Ordering::Relaxed), Ok(0));
+        let bytes = words[0].to_ne_bytes();
+        let expected = match order {
+            Order::BE => total.to_be_bytes(),
+            Order::LE => total.to_le_bytes(),
+            Order::CDAB => total.rotate_left(16).to_be_bytes(),
+            Order::BADC => total.rotate_left(16).to_le_bytes(),
+        };
+        assert_eq!(bytes[4..], expected, "{order:?}");
+    }
+}
+
+#[test]
+fn compare_exchange() {
+    for order in ORDERS {
+        let mut words = [0_u32; 2];
+        // SAFETY: The words are valid for reads and writes of 8 bytes and accessed via `shared`
+        // only with consistent sizes.
+        let shared = unsafe { Shared::new(words.as_mut_ptr().cast(), 8, order) };
+        shared
+            .atomic_store_u32(4, 0x0102_0304, Ordering::Relaxed)
+            .unwrap();
+        assert_eq!(
+            shared.atomic_compare_exchange_u32(4, 0, 1, Ordering::Relaxed, Ordering::Relaxed),
+            Ok(Err(0x0102_0304))
+        );
+        assert_eq!(
+            shared.atomic_compare_exchange_u32(
+                4,
+                0x0102_0304,
+                0x0506_0708,
+                Ordering::Relaxed,
+                Ordering::Relaxed
+            ),
+            Ok(Ok(0x0102_0304))
+        );
+        assert_eq!(
+            shared.atomic_load_u32(4, Ordering::Relaxed),
+            Ok(0x0506_0708)
+        );
+    }
+}
+
+#[test]
+fn errors() {
+    let mut words = [0_u64; 2];
+    for order in ORDERS {
+        // SAFETY: The words are valid for reads and writes of 16 bytes and accessed via `shared`
+        // only with consistent sizes.
+        let shared = unsafe { Shared::new(words.as_mut_ptr().cast(), 16, order) };
+        assert_eq!(
+            shared.atomic_u32(0).map(|_| ()),
+            if shared.is_native() {
+                Ok(())
+            } else {
+                Err(Error::NotNative { order })
+            }
+        );
+        assert_eq!(
+            shared.atomic_load_u32(2, Ordering::Relaxed),
+            Err(Error::Misaligned {
+                offset: 2,
+                align: 4
+            })
+        );
+        assert_eq!(
+            shared.atomic_load_u64(4, Ordering::Relaxed),
+            Err(Error::Misaligned {
+                offset: 4,
+                align: 8
+            })
+        );
+        assert_eq!(
+            shared.atomic_fetch_add_u64(16, 1, Ordering::Relaxed),
+            Err(Error::OutOfBounds {
+                offset: 16,
+                word_size: 8,
+                length: 16
+            })
+        );
+    }
+    let mut bytes = [0; 3];
+    // SAFETY: The bytes are accessed via `shared` only with consistent sizes.
+    let shared = unsafe { Shared::from_slice(&mut bytes, Order::BE) };
+    assert_eq!(shared.len(), 3);
+    assert!(matches!(
+        shared.atomic_load_u32(0, Ordering::Relaxed),
+        Err(Error::OutOfBounds { .. })
+    ));
+}
+
+#[test]
+fn swap_size() {
+    for order in ORDERS {
+        let mut words = [0_u64; 2];
+        // SAFETY: The words are valid for reads and writes of 16 bytes and accessed via `shared`
+        // only with consistent sizes.
+        let shared = unsafe { Shared::new(words.as_mut_ptr().cast(), 16, order) }
+            .with_swap_size(8)
+            .unwrap();
+        assert_eq!(shared.swap_size(), 8);
+        shared
+            .atomic_store_u32(0, 0x0102_0304, Ordering::Relaxed)
+            .unwrap();
+        shared
+            .atomic_fetch_add_u32(12, 0x0506_0708, Ordering::Relaxed)
+            .unwrap();
+        assert_eq!(
+            shared.atomic_load_u32(0, Ordering::Relaxed),
+            Ok(0x0102_0304)
+        );
+        if !order.is_be() {
+            assert_eq!(
+                shared.atomic_load_u32(6, Ordering::Relaxed),
+                Err(Error::Crossing {
+                    offset: 6,
+                    word_size: 4,
+                    swap_size: 8
+                })
+            );
+        }
+        let mut bytes = [0; 16];
+        for (chunk, word) in bytes.chunks_exact_mut(8).zip(words) {
+            chunk.copy_from_slice(&word.to_ne_bytes());
+        }
+        let view = Dyn::<_, 8>::new(&bytes[..], order);
+        assert_eq!(view.u32(0), 0x0102_0304, "{order:?}");
+        assert_eq!(view.u32(12), 0x0506_0708, "{order:?}");
+        assert_eq!(view.u32(4), 0, "{order:?}");
+    }
+    let mut bytes = [0; 8];
+    // SAFETY: The bytes are accessed via `shared` only with consistent sizes.
+    let shared = unsafe { Shared::from_slice(&mut bytes, Order::LE) };
+    assert!(matches!(
+        shared.with_swap_size(3),
+        Err(Error::SwapSize { swap_size: 3 })
+    ));
+}
@@ -0,0 +1,158 @@
+//! Tests of [`InSituBuilder`].
+
+#![cfg(feature = "alloc")]
+
+use in_situ::{Error, InSitu, InSituBuilder, Order};
+
+#[test]
+fn fill() {
+    let mut builder = InSituBuilder::new(Vec::new(), Order::BE);
+    let length = builder.placeholder::<u16>();
+    let offset = builder.placeholder::<u32>();
+    let marker = builder.marker();
+    builder.put_u32(7);
+    assert_eq!(builder.fill_length(&length, marker), Ok(()));
+    assert_eq!(builder.fill_offset(&offset), Ok(()));
+    let packet = builder.finish().unwrap();
+    assert_eq!(packet.u16(0), 4);
+    assert_eq!(packet.u32(2), 10);
+}
+
+#[test]
+fn unissued() {
+    let mut builder = InSituBuilder::new(Vec::new(), Order::BE);
+    let mut other = InSituBuilder::new(Vec::new(), Order::BE);
+    other.put_u64(0);
+    let marker = other.marker();
+    let foreign = other.placeholder::<u16>();
+    let length = builder.placeholder::<u16>();
+    assert_eq!(
+        builder.fill_length(&length, marker),
+        Err(Error::Unissued { offset: 8 })
+    );
+    builder.put_u64(0);
+    let length = builder.placeholder::<u16>();
+    assert_eq!(
+        builder.fill_length(&length, marker),
+        Err(Error::Unissued { offset: 8 })
+    );
+    assert_eq!(builder.fill(foreign, 1), Err(Error::Unissued { offset: 8 }));
+    assert!(matches!(builder.finish(), Err(Error::Unfilled { .. })));
+}
+
+#[test]
+fn overflow() {
+    let mut builder = InSituBuilder::new(Vec::new(), Order::BE);
+    let length = builder.placeholder::<u8>();
+    let offset = builder.placeholder::<u8>();
+    let marker = builder.marker();
+    builder.put_bytes(&[0; 256]);
+    assert_eq!(
+        builder.fill_length(&length, marker),
+        Err(Error::Overflow { word_size: 1 })
+    );
+    assert_eq!(
+        builder.fill_offset(&offset),
+        Err(Error::Overflow { word_size: 1 })
+    );
+    assert_eq!(builder.fill(length, 0xff), Ok(()));
+    assert_eq!(builder.fill(offset, 0xfe), Ok(()));
+    let packet = builder.finish().unwrap();
+    assert_eq!(packet.u8(0), 0xff);
+    assert_eq!(packet.u8(1), 0xfe);
+}
+
+#[test]
+fn refill() {
+    let mut builder = InSituBuilder::new(Vec::new(), Order::LE);
+    let length = builder.placeholder::<u16>();
+    let marker = builder.marker();
+    builder.put_u8(0);
+    assert_eq!(builder.fill_length(&length, marker), Ok(()));
+    assert_eq!(
+        builder.fill_length(&length, marker),
+        Err(Error::Unissued { offset: 0 })
+    );
+    assert_eq!(builder.fill(length, 0), Err(Error::Unissued { offset: 0 }));
+    assert_eq!(builder.finish().unwrap().u16(0), 1);
+}
+
+#[test]
+fn pad_to() {
+    let mut builder = InSituBuilder::new(Vec::new(), Order::BE);
+    assert_eq!(builder.pad_to(4), Ok(()));
+    assert!(builder.is_empty());
+    builder.put_u8(1);
+    assert_eq!(builder.pad_to(4), Ok(()));
+    assert_eq!(builder.len(), 4);
+    assert_eq!(builder.pad_to(1), Ok(()));
+    assert_eq!(builder.len(), 4);
+    builder.put_u16(2);
+    assert_eq!(builder.pad_to(0), Err(Error::Align { align: 0 }));
+    assert_eq!(builder.pad_to(3), Err(Error::Align { align: 3 }));
+    assert_eq!(builder.len(), 6);
+    assert_eq!(builder.pad_to(8), Ok(()));
+    assert_eq!(builder.as_ref(), [1, 0, 0, 0, 0, 2, 0, 0]);
+}
+
+#[test]
+fn word_swap() {
+    for order in [Order::BE, Order::LE, Order::CDAB, Order::BADC] {
+        let mut builder = InSituBuilder::new(Vec::new(), order);
+        if order.is_word_swapped() {
+            assert_eq!(
+                builder.try_put_u24(1),
+                Err(Error::WordSwap { word_size: 3 })
+            );
+            assert_eq!(
+                builder.try_put_i24(-1),
+                Err(Error::WordSwap { word_size: 3 })
+            );
+            assert_eq!(
+                builder.try_put_uint(1, 5),
+                Err(Error::WordSwap { word_size: 5 })
+            );
+            assert_eq!(
+                builder.try_put_int128(-1, 9),
+                Err(Error::WordSwap { word_size: 9 })
+            );
+            assert!(builder.is_empty(), "{order:?}");
+        } else {
+            assert_eq!(builder.try_put_u24(0x01_0203), Ok(()));
+            assert_eq!(builder.try_put_i24(-2), Ok(()));
+            assert_eq!(builder.try_put_uint(0x04_0506_0708, 5), Ok(()));
+            assert_eq!(builder.try_put_int128(-3, 9), Ok(()));
+            let packet = builder.clone().finish().unwrap();
+            assert_eq!(packet.u24(0), 0x01_0203, "{order:?}");
+            assert_eq!(packet.i24(3), -2, "{order:?}");
+            assert_eq!(packet.uint(6, 5), 0x04_0506_0708, "{order:?}");
+            assert_eq!(packet.int128(11, 9), -3, "{order:?}");
+        }
+        assert_eq!(builder.try_put_uint(0x0102, 2), Ok(()));
+        assert_eq!(builder.try_put_int(-4, 6), Ok(()));
+        assert_eq!(
+            builder.try_put_uint(0, 0),
+            Err(Error::WordSize {
+                word_size: 0,
+                max: 8
+            })
+        );
+        assert_eq!(
+            builder.try_put_uint128(0, 17),
+            Err(Error::WordSize {
+                word_size: 17,
+                max: 16
+            })
+        );
+        let length = builder.len();
+        let packet = builder.finish().unwrap();
+        assert_eq!(packet.uint(length - 8, 2), 0x0102, "{order:?}");
+        assert_eq!(packet.int(length - 6, 6), -4, "{order:?}");
+    }
+}
+
+#[test]
+#[should_panic = "word of odd size cannot be word-swapped"]
+fn put_u24_word_swapped() {
+    InSituBuilder::new(Vec::new(), Order::CDAB).put_u24(1);
+}
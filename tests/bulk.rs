@@ -0,0 +1,90 @@
+//! Tests of bulk reads and writes against per-element getters and setters.
+
+use in_situ::{Dyn, InSitu, InSituMut, InSituPrimitive, Order};
+
+/// Byte orders to test.
+const ORDERS: [Order; 4] = [Order::BE, Order::LE, Order::CDAB, Order::BADC];
+
+/// Offset of the words.
+const OFFSET: usize = 16;
+
+/// Number of the words.
+const COUNT: usize = 4;
+
+/// Bytes whose words are neither NaN nor subnormal in any byte order.
+fn pattern() -> [u8; 128] {
+    let mut bytes = [0; 128];
+    for (index, byte) in bytes.iter_mut().enumerate() {
+        *byte = u8::try_from((index * 7 + 3) % 61 + 1).unwrap();
+    }
+    bytes
+}
+
+/// Gets words per element.
+fn get<T: InSituPrimitive, V: InSitu>(view: &V) -> Vec<T> {
+    (0..COUNT)
+        .map(|index| view.get::<T>(OFFSET + index * T::SIZE))
+        .collect()
+}
+
+/// Sets words per element.
+fn set<T: InSituPrimitive, V: InSituMut>(view: &mut V, values: &[T]) {
+    for (index, &value) in values.iter().enumerate() {
+        view.set::<T>(OFFSET + index * T::SIZE, value);
+    }
+}
+
+macro_rules! bulk {
+    ($($name:ident: $ty:ty => $read:ident, $try_read:ident, $write:ident, $try_write:ident;)*) => {
+        $(
+            #[test]
+            fn $name() {
+                bulk!(@swap $ty, $read, $try_read, $write, $try_write, 0, 2, 8, 16);
+            }
+        )*
+    };
+    (@swap $ty:ty, $read:ident, $try_read:ident, $write:ident, $try_write:ident,
+        $($swap_size:literal),*) => {
+        $(
+            for order in ORDERS {
+                let mut bytes = pattern();
+                let view = Dyn::<_, $swap_size>::new(&mut bytes[..], order);
+                let values = get::<$ty, _>(&view);
+                let mut dst = [<$ty>::default(); COUNT];
+                view.$read(OFFSET, &mut dst);
+                assert_eq!(dst[..], values[..], "{order:?}, swap size {}", $swap_size);
+                let mut dst = [<$ty>::default(); COUNT];
+                view.$try_read(OFFSET, &mut dst).unwrap();
+                assert_eq!(dst[..], values[..], "{order:?}, swap size {}", $swap_size);
+                assert!(view.$try_read(128, &mut dst).is_err());
+
+                let values = values.iter().rev().copied().collect::<Vec<$ty>>();
+                let mut expected = pattern();
+                set(&mut Dyn::<_, $swap_size>::new(&mut expected[..], order), &values);
+                let mut bytes = pattern();
+                Dyn::<_, $swap_size>::new(&mut bytes[..], order).$write(OFFSET, &values);
+                assert_eq!(bytes, expected, "{order:?}, swap size {}", $swap_size);
+                let mut bytes = pattern();
+                let mut view = Dyn::<_, $swap_size>::new(&mut bytes[..], order);
+                view.$try_write(OFFSET, &values).unwrap();
+                assert!(view.$try_write(128, &values).is_err());
+                assert_eq!(bytes, expected, "{order:?}, swap size {}", $swap_size);
+            }
+        )*
+    };
+}
+
+bulk! {
+    u16s: u16 => read_u16_into, try_read_u16_into, set_u16_from_slice, try_set_u16_from_slice;
+    u32s: u32 => read_u32_into, try_read_u32_into, set_u32_from_slice, try_set_u32_from_slice;
+    u64s: u64 => read_u64_into, try_read_u64_into, set_u64_from_slice, try_set_u64_from_slice;
+    u128s: u128 =>
+        read_u128_into, try_read_u128_into, set_u128_from_slice, try_set_u128_from_slice;
+    i16s: i16 => read_i16_into, try_read_i16_into, set_i16_from_slice, try_set_i16_from_slice;
+    i32s: i32 => read_i32_into, try_read_i32_into, set_i32_from_slice, try_set_i32_from_slice;
+    i64s: i64 => read_i64_into, try_read_i64_into, set_i64_from_slice, try_set_i64_from_slice;
+    i128s: i128 =>
+        read_i128_into, try_read_i128_into, set_i128_from_slice, try_set_i128_from_slice;
+    f32s: f32 => read_f32_into, try_read_f32_into, set_f32_from_slice, try_set_f32_from_slice;
+    f64s: f64 => read_f64_into, try_read_f64_into, set_f64_from_slice, try_set_f64_from_slice;
+}
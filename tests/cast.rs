@@ -0,0 +1,196 @@
+//! Tests of casting slices and structs in place.
+#![cfg(any(feature = "alloc", feature = "bytemuck", feature = "zerocopy"))]
+
+#[cfg(any(feature = "bytemuck", feature = "zerocopy"))]
+use in_situ::InSituMut;
+use in_situ::{Dyn, Error, InSitu, Order};
+#[cfg(feature = "alloc")]
+use std::borrow::Cow;
+
+/// Native byte order.
+const NATIVE: Order = if cfg!(target_endian = "big") {
+    Order::BE
+} else {
+    Order::LE
+};
+
+/// Non-native byte order.
+const FOREIGN: Order = if cfg!(target_endian = "big") {
+    Order::LE
+} else {
+    Order::BE
+};
+
+/// Bytes aligned to 16 bytes.
+#[repr(align(16))]
+struct Aligned([u8; 32]);
+
+/// Creates aligned bytes counting up from zero.
+fn aligned() -> Aligned {
+    let mut bytes = [0; 32];
+    for (byte, value) in bytes.iter_mut().zip(0..) {
+        *byte = value;
+    }
+    Aligned(bytes)
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn cast_slice() {
+    let bytes = aligned();
+    let view = Dyn::<_>::new(&bytes.0[..], NATIVE);
+    let words = view.cast_slice::<u32>(4, 3).unwrap();
+    assert!(matches!(words, Cow::Borrowed(_)));
+    assert_eq!(*words, [view.u32(4), view.u32(8), view.u32(12)]);
+    assert_eq!(words.as_ptr().cast(), bytes.0[4..].as_ptr());
+    let words = view.cast_slice::<u32>(2, 2).unwrap();
+    assert!(matches!(words, Cow::Owned(_)));
+    assert_eq!(*words, [view.u32(2), view.u32(6)]);
+    let view = Dyn::<_>::new(&bytes.0[..], FOREIGN);
+    let words = view.cast_slice::<u64>(8, 2).unwrap();
+    assert!(matches!(words, Cow::Owned(_)));
+    assert_eq!(*words, [view.u64(8), view.u64(16)]);
+    assert_eq!(
+        words[0],
+        u64::from_ne_bytes(*b"\x08\x09\x0a\x0b\x0c\x0d\x0e\x0f").swap_bytes()
+    );
+    let bytes = view.cast_slice::<u8>(1, 3).unwrap();
+    assert!(matches!(bytes, Cow::Borrowed([1, 2, 3])));
+    assert!(view.cast_slice::<u16>(0, 0).unwrap().is_empty());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn cast_slice_swap_size() {
+    let bytes = aligned();
+    let view = Dyn::<_, 8>::new(&bytes.0[..], Order::LE);
+    let words = view.cast_slice::<u16>(8, 4).unwrap();
+    assert!(matches!(words, Cow::Owned(_)));
+    assert_eq!(
+        *words,
+        [view.u16(8), view.u16(10), view.u16(12), view.u16(14)]
+    );
+    assert_eq!(words[0], u16::from_le_bytes([14, 15]));
+    let words = view.cast_slice::<u64>(8, 2).unwrap();
+    assert_eq!(*words, [view.u64(8), view.u64(16)]);
+    if NATIVE == Order::LE {
+        assert!(matches!(words, Cow::Borrowed(_)));
+    }
+    let view = Dyn::<_, 8>::new(&bytes.0[..], Order::BE);
+    let words = view.cast_slice::<u16>(8, 2).unwrap();
+    assert_eq!(
+        *words,
+        [u16::from_be_bytes([8, 9]), u16::from_be_bytes([10, 11])]
+    );
+    if NATIVE == Order::BE {
+        assert!(matches!(words, Cow::Borrowed(_)));
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn cast_slice_errors() {
+    let bytes = aligned();
+    let view = Dyn::<_, 4>::new(&bytes.0[..], Order::LE);
+    assert_eq!(
+        view.cast_slice::<u16>(30, 2),
+        Err(Error::OutOfBounds {
+            offset: 30,
+            word_size: 4,
+            length: 32
+        })
+    );
+    assert_eq!(
+        view.cast_slice::<u16>(3, 2),
+        Err(Error::Crossing {
+            offset: 3,
+            word_size: 2,
+            swap_size: 4
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "bytemuck")]
+fn bytemuck() {
+    let mut bytes = aligned();
+    let mut view = Dyn::<_>::new(&mut bytes.0[..], NATIVE);
+    let words = *view.bytemuck_ref::<[u32; 2]>(8).unwrap();
+    assert_eq!(words, [view.u32(8), view.u32(12)]);
+    view.bytemuck_mut::<[u32; 2]>(8).unwrap()[1] = 0x0102_0304;
+    assert_eq!(view.u32(12), 0x0102_0304);
+    assert_eq!(
+        view.bytemuck_ref::<[u32; 2]>(2),
+        Err(Error::Misaligned {
+            offset: 2,
+            align: 4
+        })
+    );
+    assert_eq!(
+        view.bytemuck_mut::<[u32; 2]>(28),
+        Err(Error::OutOfBounds {
+            offset: 28,
+            word_size: 8,
+            length: 32
+        })
+    );
+    let mut view = Dyn::<_>::new(&mut bytes.0[..], FOREIGN);
+    assert_eq!(
+        view.bytemuck_ref::<u32>(0),
+        Err(Error::NotNative { order: FOREIGN })
+    );
+    assert_eq!(
+        view.bytemuck_mut::<u32>(0),
+        Err(Error::NotNative { order: FOREIGN })
+    );
+    let view = Dyn::<_, 8>::new(&mut bytes.0[..], Order::LE);
+    assert_eq!(
+        view.bytemuck_ref::<u32>(0),
+        Err(Error::NotNative { order: Order::LE })
+    );
+    let view = Dyn::<_>::new(&mut bytes.0[..], Order::CDAB);
+    assert_eq!(
+        view.bytemuck_ref::<u32>(0),
+        Err(Error::NotNative { order: Order::CDAB })
+    );
+}
+
+#[test]
+#[cfg(feature = "zerocopy")]
+fn zerocopy() {
+    let mut bytes = aligned();
+    let mut view = Dyn::<_>::new(&mut bytes.0[..], NATIVE);
+    let words = *view.zerocopy_ref::<[u64; 2]>(16).unwrap();
+    assert_eq!(words, [view.u64(16), view.u64(24)]);
+    view.zerocopy_mut::<[u64; 2]>(16).unwrap()[0] = 0x0102_0304_0506_0708;
+    assert_eq!(view.u64(16), 0x0102_0304_0506_0708);
+    assert_eq!(
+        view.zerocopy_ref::<u64>(4),
+        Err(Error::Misaligned {
+            offset: 4,
+            align: 8
+        })
+    );
+    assert_eq!(
+        view.zerocopy_mut::<u64>(32),
+        Err(Error::OutOfBounds {
+            offset: 32,
+            word_size: 8,
+            length: 32
+        })
+    );
+    let mut view = Dyn::<_>::new(&mut bytes.0[..], FOREIGN);
+    assert_eq!(
+        view.zerocopy_ref::<u64>(0),
+        Err(Error::NotNative { order: FOREIGN })
+    );
+    assert_eq!(
+        view.zerocopy_mut::<u64>(0),
+        Err(Error::NotNative { order: FOREIGN })
+    );
+    let view = Dyn::<_, 16>::new(&mut bytes.0[..], Order::LE);
+    assert_eq!(
+        view.zerocopy_ref::<u64>(0),
+        Err(Error::NotNative { order: Order::LE })
+    );
+}
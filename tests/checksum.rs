@@ -0,0 +1,78 @@
+//! Tests of checksums against known answers.
+
+use in_situ::{Be, Dyn, Error, InSitu, InSituMut, Le, Order};
+
+/// IPv4 header with checksum `0xb861` at offset 10.
+const IPV4: [u8; 20] = [
+    0x45, 0x00, 0x00, 0x73, 0x00, 0x00, 0x40, 0x00, 0x40, 0x11, 0xb8, 0x61, 0xc0, 0xa8, 0x00, 0x01,
+    0xc0, 0xa8, 0x00, 0xc7,
+];
+
+#[test]
+fn crc32() {
+    assert_eq!(Be(&b"123456789"[..]).crc32(0..9), Ok(0xcbf4_3926));
+    assert_eq!(Le::<_>(&b"123456789"[..]).crc32(0..9), Ok(0xcbf4_3926));
+    assert_eq!(Be(&b""[..]).crc32(0..0), Ok(0));
+    assert!(matches!(
+        Be(&b"123"[..]).crc32(0..4),
+        Err(Error::OutOfBounds { .. })
+    ));
+}
+
+#[test]
+fn crc16() {
+    let view = Be(&b"123456789"[..]);
+    assert_eq!(view.crc16(0..9, 0x1021), Ok(0x31c3));
+    assert_eq!(view.crc16(0..9, 0x8005), Ok(0xfee8));
+}
+
+#[test]
+fn adler32() {
+    assert_eq!(Be(&b"Wikipedia"[..]).adler32(0..9), Ok(0x11e6_0398));
+    assert_eq!(Be(&b""[..]).adler32(0..0), Ok(1));
+    let bytes = vec![0xff; 3 * 5552 + 7];
+    let (mut a, mut b) = (1, 0);
+    for &byte in &bytes {
+        a = (a + u32::from(byte)) % 65521;
+        b = (b + a) % 65521;
+    }
+    assert_eq!(Be(&bytes[..]).adler32(0..bytes.len()), Ok((b << 16) | a));
+}
+
+#[test]
+fn internet_checksum() {
+    let view = Be(&IPV4[..]);
+    assert_eq!(view.internet_checksum(0..20), Ok(0));
+    assert_eq!(view.internet_checksum(0..1), Ok(!0x4500));
+    let mut bytes = IPV4;
+    for order in [Order::BE, Order::LE] {
+        let mut view = Dyn::<_>::new(&mut bytes[..], order);
+        view.set_u16(10, 0xffff);
+        assert_eq!(view.update_checksum_u16(10, 0..20), Ok(view.u16(10)));
+        assert_eq!(view.as_ref()[10..12], [0xb8, 0x61], "{order:?}");
+        assert_eq!(view.internet_checksum(0..20), Ok(0), "{order:?}");
+    }
+}
+
+#[test]
+fn incremental() {
+    for order in [Order::BE, Order::LE] {
+        let mut bytes = IPV4;
+        let mut view = Dyn::<_>::new(&mut bytes[..], order);
+        view.set_u32_checksummed(16, 0x0a00_0001, 10).unwrap();
+        view.set_u16_checksummed(2, 0x0054, 10).unwrap();
+        view.set_u16_checksummed(4, 0xabcd, 10).unwrap();
+        let incremental = view.u16(10);
+        assert_eq!(view.internet_checksum(0..20), Ok(0), "{order:?}");
+        assert_eq!(view.update_checksum_u16(10, 0..20), Ok(incremental));
+        assert_eq!(
+            view.set_u16_checksummed(2, 0, 20),
+            Err(Error::OutOfBounds {
+                offset: 20,
+                word_size: 2,
+                length: 20
+            })
+        );
+        assert_eq!(view.u16(2), 0x0054, "{order:?}");
+    }
+}
@@ -0,0 +1,22 @@
+//! Tests of [`InSituCursor`].
+
+use in_situ::{Be, Error, InSituCursor};
+
+#[test]
+fn align_to() {
+    let view = Be(&[0; 8][..]);
+    let mut cursor = InSituCursor::new(&view);
+    cursor.set_position(1);
+    assert_eq!(cursor.align_to(4), Ok(()));
+    assert_eq!(cursor.position(), 4);
+    assert_eq!(cursor.align_to(4), Ok(()));
+    assert_eq!(cursor.position(), 4);
+    assert_eq!(cursor.align_to(0), Err(Error::Align { align: 0 }));
+    assert_eq!(cursor.align_to(3), Err(Error::Align { align: 3 }));
+    assert_eq!(cursor.position(), 4);
+    cursor.set_position(usize::MAX);
+    assert!(matches!(
+        cursor.align_to(16),
+        Err(Error::OutOfBounds { .. })
+    ));
+}
@@ -0,0 +1,147 @@
+//! Tests of `#[derive(InSitu)]`.
+
+#![cfg(feature = "derive")]
+
+use in_situ::{Error, InSitu, Order, Volatile};
+
+#[derive(Debug, Clone, Copy, PartialEq, InSitu)]
+#[in_situ(wrapper = HeaderView, volatile = HeaderRegs, size = 36)]
+struct Header {
+    #[in_situ(offset = 0)]
+    version: u8,
+    #[in_situ(offset = 1)]
+    flags: bool,
+    #[in_situ(offset = 2)]
+    length: u16,
+    #[in_situ(offset = 4)]
+    id: u32,
+    #[in_situ(offset = 8, ty = uint, word_size = 6)]
+    sequence: u64,
+    #[in_situ(offset = 14)]
+    delta: i16,
+    #[in_situ(offset = 16)]
+    scale: f64,
+    #[in_situ(offset = 24)]
+    stamp: i64,
+    #[in_situ(offset = 32, ty = u24)]
+    tag: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, InSitu)]
+#[in_situ(wrapper = RecordView)]
+struct Record {
+    #[in_situ(offset = 0)]
+    id: u32,
+    #[in_situ(offset = 4, ty = int, word_size = 6)]
+    delta: i64,
+    #[in_situ(offset = 10, ty = uint, word_size = 1)]
+    kind: u64,
+}
+
+const HEADER: Header = Header {
+    version: 4,
+    flags: true,
+    length: 0x0102,
+    id: 0x0304_0506,
+    sequence: 0x0708_090a_0b0c,
+    delta: -2,
+    scale: 1.5,
+    stamp: -0x0d0e_0f10,
+    tag: 0x0011_1213,
+};
+
+#[test]
+fn size() {
+    assert_eq!(HeaderView::<&[u8]>::SIZE, 36);
+    assert_eq!(RecordView::<&[u8]>::SIZE, 11);
+    assert!(HeaderView::new(&[0; 35][..], Order::BE).is_err());
+}
+
+#[test]
+fn load_store() {
+    for order in [Order::BE, Order::LE, Order::CDAB, Order::BADC] {
+        let mut bytes = [0; 36];
+        let view = HeaderView::new(&mut bytes[..], order);
+        if order.is_word_swapped() {
+            assert_eq!(view, Err(Error::WordSwap { word_size: 3 }), "{order:?}");
+            continue;
+        }
+        let mut view = view.unwrap();
+        view.store(&HEADER);
+        assert_eq!(view.load(), HEADER, "{order:?}");
+        assert_eq!(view.id(), 0x0304_0506, "{order:?}");
+        assert_eq!(view.tag(), 0x0011_1213, "{order:?}");
+        view.set_id(0x1112_1314);
+        assert_eq!(view.u32(4), 0x1112_1314, "{order:?}");
+        view.set_tag(0x00ff_fefd);
+        assert_eq!(view.u24(32), 0x00ff_fefd, "{order:?}");
+    }
+}
+
+#[test]
+fn load_store_word_swapped() {
+    let record = Record {
+        id: 0x0102_0304,
+        delta: -0x0506_0708_090a,
+        kind: 0x0b,
+    };
+    for order in [Order::BE, Order::LE, Order::CDAB, Order::BADC] {
+        let mut bytes = [0; 11];
+        let mut view = RecordView::new(&mut bytes[..], order).unwrap();
+        view.store(&record);
+        assert_eq!(view.load(), record, "{order:?}");
+        assert_eq!(view.int(4, 6), -0x0506_0708_090a, "{order:?}");
+    }
+}
+
+#[test]
+fn load_store_be() {
+    let mut bytes = [0; 36];
+    let mut view = HeaderView::new(&mut bytes[..], Order::BE).unwrap();
+    view.store(&HEADER);
+    assert_eq!(
+        bytes[..16],
+        [4, 1, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 0xff, 0xfe]
+    );
+}
+
+#[test]
+fn volatile() {
+    for order in [Order::BE, Order::LE, Order::CDAB, Order::BADC] {
+        let mut words = [0_u64; 5];
+        // SAFETY: The words are valid for reads and writes of their size and exclusively borrowed.
+        let bytes = unsafe {
+            core::slice::from_raw_parts_mut(words.as_mut_ptr().cast(), size_of_val(&words))
+        };
+        let regs = HeaderRegs::new(Volatile::from_slice(&mut bytes[..], order));
+        if order.is_word_swapped() {
+            assert!(matches!(regs, Err(Error::WordSwap { word_size: 3 })));
+            continue;
+        }
+        let mut regs = regs.unwrap();
+        regs.store(&HEADER).unwrap();
+        assert_eq!(regs.load(), Ok(HEADER), "{order:?}");
+        regs.set_tag(0x00ff_fefd).unwrap();
+        assert_eq!(regs.tag(), Ok(0x00ff_fefd), "{order:?}");
+        regs.set_tag(HEADER.tag).unwrap();
+        let mut expected = [0; 40];
+        HeaderView::new(&mut expected[..], order)
+            .unwrap()
+            .store(&HEADER);
+        assert_eq!(bytes[..], expected, "{order:?}");
+    }
+    let mut bytes = [0; 35];
+    assert!(matches!(
+        HeaderRegs::new(Volatile::from_slice(&mut bytes, Order::BE)),
+        Err(Error::OutOfBounds {
+            offset: 0,
+            word_size: 36,
+            length: 35
+        })
+    ));
+}
+
+#[test]
+fn compile_fail() {
+    trybuild::TestCases::new().compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,123 @@
+//! Tests of byte order detection via magic numbers.
+
+use in_situ::{Dyn, InSituMut, Magic, Order};
+
+/// Byte orders to test.
+const ORDERS: [Order; 4] = [Order::BE, Order::LE, Order::CDAB, Order::BADC];
+
+/// Variants of pcap files.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Pcap {
+    Micro,
+    Nano,
+}
+
+/// Table of pcap magics.
+const PCAP: [Magic<'static, Pcap>; 4] = [
+    Magic::new(0, &[0xa1, 0xb2, 0xc3, 0xd4], Order::BE, Pcap::Micro),
+    Magic::new(0, &[0xd4, 0xc3, 0xb2, 0xa1], Order::LE, Pcap::Micro),
+    Magic::new(0, &[0xa1, 0xb2, 0x3c, 0x4d], Order::BE, Pcap::Nano),
+    Magic::new(0, &[0x4d, 0x3c, 0xb2, 0xa1], Order::LE, Pcap::Nano),
+];
+
+#[test]
+fn detect_u16() {
+    for order in [Order::BE, Order::LE] {
+        let mut bytes = [0; 4];
+        Dyn::<_>::new(&mut bytes[..], order).set_u16(1, 0xfeff);
+        assert_eq!(Order::detect_u16(&bytes, 1, 0xfeff), Some(order));
+    }
+    assert_eq!(Order::detect_u16(&[0xab, 0xab], 0, 0xabab), Some(Order::BE));
+    assert_eq!(Order::detect_u16(&[0xfe, 0xfe], 0, 0xfeff), None);
+    assert_eq!(Order::detect_u16(&[0xfe, 0xff], 1, 0xfeff), None);
+    assert_eq!(Order::detect_u16(&[0xfe, 0xff], 3, 0xfeff), None);
+}
+
+#[test]
+fn detect_u32() {
+    for order in ORDERS {
+        let mut bytes = [0; 6];
+        Dyn::<_>::new(&mut bytes[..], order).set_u32(2, 0x0a0b_0c0d);
+        assert_eq!(
+            Order::detect_u32(&bytes, 2, 0x0a0b_0c0d),
+            Some(order),
+            "{order:?}"
+        );
+    }
+    assert_eq!(
+        Order::detect_u32(&[0x0a, 0x0b, 0x0c, 0x0e], 0, 0x0a0b_0c0d),
+        None
+    );
+    assert_eq!(
+        Order::detect_u32(&[0x0a, 0x0b, 0x0c, 0x0d], 1, 0x0a0b_0c0d),
+        None
+    );
+    assert_eq!(
+        Order::detect_u32(&[0x0a, 0x0b, 0x0c, 0x0d], 5, 0x0a0b_0c0d),
+        None
+    );
+}
+
+#[test]
+fn detect_u32_ambiguous() {
+    // Invariant under word-swapping, so BE precedes CDAB and LE precedes BADC.
+    let magic = 0x0a0b_0a0b;
+    assert_eq!(
+        Order::detect_u32(&[0x0a, 0x0b, 0x0a, 0x0b], 0, magic),
+        Some(Order::BE)
+    );
+    assert_eq!(
+        Order::detect_u32(&[0x0b, 0x0a, 0x0b, 0x0a], 0, magic),
+        Some(Order::LE)
+    );
+    // Invariant under byte-swapping within words, so BE precedes BADC and LE precedes CDAB.
+    let magic = 0x0a0a_0b0b;
+    assert_eq!(
+        Order::detect_u32(&[0x0a, 0x0a, 0x0b, 0x0b], 0, magic),
+        Some(Order::BE)
+    );
+    assert_eq!(
+        Order::detect_u32(&[0x0b, 0x0b, 0x0a, 0x0a], 0, magic),
+        Some(Order::LE)
+    );
+}
+
+#[test]
+fn detect() {
+    for (bytes, expected) in [
+        ([0xa1, 0xb2, 0xc3, 0xd4], (Order::BE, Pcap::Micro)),
+        ([0xd4, 0xc3, 0xb2, 0xa1], (Order::LE, Pcap::Micro)),
+        ([0xa1, 0xb2, 0x3c, 0x4d], (Order::BE, Pcap::Nano)),
+        ([0x4d, 0x3c, 0xb2, 0xa1], (Order::LE, Pcap::Nano)),
+    ] {
+        assert_eq!(Order::detect(&bytes, &PCAP), Some(expected));
+    }
+    assert_eq!(Order::detect(&[0xa1, 0xb2, 0xc3, 0xd5], &PCAP), None);
+    assert_eq!(Order::detect(&[0xa1, 0xb2, 0xc3], &PCAP), None);
+    assert_eq!(Order::detect(&[], &PCAP), None);
+    assert_eq!(Order::detect::<Pcap>(&[0xa1, 0xb2, 0xc3, 0xd4], &[]), None);
+}
+
+#[test]
+fn detect_ambiguous() {
+    // Same bytes announce a word-swapped and a plain order, the first entry wins.
+    let table = [
+        Magic::new(0, &[0x0a, 0x0b, 0x0a, 0x0b], Order::CDAB, 1),
+        Magic::new(0, &[0x0a, 0x0b, 0x0a, 0x0b], Order::BE, 2),
+    ];
+    let bytes = [0x0a, 0x0b, 0x0a, 0x0b, 0x00];
+    assert_eq!(Order::detect(&bytes, &table), Some((Order::CDAB, 1)));
+    let table = [table[1], table[0]];
+    assert_eq!(Order::detect(&bytes, &table), Some((Order::BE, 2)));
+}
+
+#[test]
+fn matches() {
+    let magic = Magic::new(2, b"II", Order::LE, ());
+    assert!(magic.matches(b"..II*"));
+    assert!(!magic.matches(b"..MM*"));
+    assert!(!magic.matches(b"..I"));
+    assert!(!magic.matches(b"."));
+    assert!(Magic::new(2, b"", Order::BE, ()).matches(b".."));
+    assert!(!Magic::new(3, b"", Order::BE, ()).matches(b".."));
+}
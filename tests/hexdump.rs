@@ -0,0 +1,26 @@
+//! Tests of [`Hexdump`](in_situ::Hexdump).
+
+use in_situ::{Be, InSitu};
+
+#[test]
+fn fields() {
+    let view = Be(&[0x45, 0x00, 0x05, 0xdc][..]);
+    let dump = view
+        .hexdump()
+        .fields(&[("total_len", 2..4), ("none", 2..2), ("payload", 4..4)])
+        .to_string();
+    assert_eq!(
+        dump,
+        "BE, swap size 0, 4 bytes\n\
+         00000000  45 00 05 dc                                       |E...|\n\
+         0x0002..0x0004 total_len = 1500 (BE)\n\
+         0x0002..0x0002 none\n\
+         0x0004..0x0004 payload\n"
+    );
+}
+
+#[test]
+fn empty() {
+    let view = Be(&[][..]);
+    assert_eq!(view.hexdump().to_string(), "BE, swap size 0, 0 bytes\n");
+}
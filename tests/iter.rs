@@ -0,0 +1,95 @@
+//! Tests of [`InSituIter`](in_situ::InSituIter).
+
+use in_situ::{Be, Dyn, InSitu, Le, Order};
+
+/// Bytes counting up from zero.
+const BYTES: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+#[test]
+fn stride() {
+    let view = Be(&BYTES[..]);
+    assert!(view.iter_u8_strided(1, 3, 5).eq([1, 4, 7, 10, 13]));
+    assert!(view.iter_u16(2, 3).eq([0x0203, 0x0405, 0x0607]));
+    assert!(
+        view.iter_u16_strided(0, 4, 4)
+            .eq([0x0001, 0x0405, 0x0809, 0x0c0d])
+    );
+    assert!(view.iter_u16_strided(6, 0, 3).eq([0x0607; 3]));
+    let view = Le::<_>(&BYTES[..]);
+    assert!(
+        view.iter_u32_strided(1, 5, 3)
+            .eq([0x0403_0201, 0x0908_0706, 0x0e0d_0c0b])
+    );
+    let view = Dyn::<_, 4>::new(&BYTES[..], Order::LE);
+    assert!(
+        view.iter_u16(0, 4)
+            .eq((0..8).step_by(2).map(|offset| view.u16(offset)))
+    );
+    assert!(view.iter_u16(0, 2).eq([0x0302, 0x0100]));
+}
+
+#[test]
+fn nth() {
+    let view = Be(&BYTES[..]);
+    let mut iter = view.iter_u8(0, 10);
+    assert_eq!(iter.nth(2), Some(2));
+    assert_eq!(iter.nth_back(2), Some(7));
+    assert_eq!(iter.next(), Some(3));
+    assert_eq!(iter.next_back(), Some(6));
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.nth(5), None);
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next_back(), None);
+    let mut iter = view.iter_u8(0, 10);
+    assert_eq!(iter.nth_back(9), Some(0));
+    assert_eq!(iter.next(), None);
+    let mut iter = view.iter_u8(0, 10);
+    assert_eq!(iter.nth(usize::MAX), None);
+    assert_eq!(iter.nth_back(usize::MAX), None);
+    assert!(view.iter_u8(0, 10).rev().eq((0..10).rev()));
+}
+
+#[test]
+fn len() {
+    let view = Be(&BYTES[..]);
+    let mut iter = view.iter_u16(0, 8);
+    assert_eq!(iter.len(), 8);
+    assert_eq!(iter.size_hint(), (8, Some(8)));
+    iter.next();
+    assert_eq!(iter.len(), 7);
+    iter.next_back();
+    assert_eq!(iter.len(), 6);
+    iter.nth(1);
+    assert_eq!(iter.len(), 4);
+    iter.nth_back(1);
+    assert_eq!(iter.len(), 2);
+    assert_eq!(iter.clone().count(), 2);
+    assert!(iter.eq([0x0607, 0x0809]));
+}
+
+#[test]
+fn empty() {
+    let view = Be(&BYTES[..]);
+    let mut iter = view.iter_u32(16, 0);
+    assert_eq!(iter.len(), 0);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next_back(), None);
+    assert_eq!(view.iter_u64(usize::MAX, 0).next(), None);
+    assert_eq!(Be(&[][..]).iter_u8(0, 0).next(), None);
+}
+
+#[test]
+#[should_panic = "out of range"]
+fn out_of_bounds_start() {
+    let view = Be(&BYTES[..]);
+    let mut iter = view.iter_u32(14, 1);
+    assert_eq!(iter.len(), 1);
+    iter.next();
+}
+
+#[test]
+#[should_panic = "out of range"]
+fn out_of_bounds_end() {
+    let view = Be(&BYTES[..]);
+    view.iter_u32_strided(0, 8, 3).next_back();
+}
@@ -0,0 +1,71 @@
+//! Tests of serializing and deserializing views via [`Layout`].
+#![cfg(feature = "serde")]
+
+use in_situ::{Dyn, Field, Kind, Layout, Order};
+use serde::de::DeserializeSeed;
+
+/// Nested layout.
+const INNER: Layout<'static> = Layout {
+    fields: &[
+        Field::new("id", 0, Kind::U16),
+        Field::new("value", 2, Kind::I24),
+    ],
+};
+
+/// Layout of the view.
+const OUTER: Layout<'static> = Layout {
+    fields: &[
+        Field::new("flag", 0, Kind::Bool),
+        Field::new("name", 1, Kind::Utf8(7)),
+        Field::new("inner", 8, Kind::Layout(&INNER)),
+        Field::new("ratio", 16, Kind::F64),
+    ],
+};
+
+/// Deserializes `json` into `bytes` via [`OUTER`].
+fn deserialize(bytes: &mut [u8], json: &str) -> serde_json::Result<()> {
+    let mut view = Dyn::<_>::new(bytes, Order::LE);
+    OUTER
+        .view_mut(&mut view)
+        .deserialize(&mut serde_json::Deserializer::from_str(json))
+}
+
+/// Serializes `bytes` via [`OUTER`].
+fn serialize(bytes: &[u8]) -> String {
+    serde_json::to_string(&OUTER.view(&Dyn::<_>::new(bytes, Order::LE))).unwrap()
+}
+
+#[test]
+fn round_trip() {
+    let json = r#"{"flag":true,"name":"abc","inner":{"id":258,"value":-3},"ratio":0.5}"#;
+    let mut bytes = [0xff; 24];
+    deserialize(&mut bytes, json).unwrap();
+    assert_eq!(bytes[1..8], *b"abc\0\0\0\0");
+    assert_eq!(serialize(&bytes), json);
+    let mut again = [0; 24];
+    deserialize(&mut again, &serialize(&bytes)).unwrap();
+    assert_eq!(again[..13], bytes[..13]);
+    assert_eq!(again[16..], bytes[16..]);
+    assert_eq!(serialize(&again), json);
+}
+
+#[test]
+fn reject() {
+    let mut bytes = [0; 24];
+    let error = deserialize(&mut bytes, r#"{"flag":true,"flag":false}"#).unwrap_err();
+    assert!(
+        error.to_string().contains("duplicate field `flag`"),
+        "{error}"
+    );
+    let error = deserialize(&mut bytes, r#"{"inner":{"id":1,"id":2}}"#).unwrap_err();
+    assert!(
+        error.to_string().contains("duplicate field `id`"),
+        "{error}"
+    );
+    let error = deserialize(&mut bytes, r#"{"unknown":1}"#).unwrap_err();
+    assert!(
+        error.to_string().contains("unknown field `unknown`"),
+        "{error}"
+    );
+    assert!(deserialize(&mut bytes, r#"{"name":"too long"}"#).is_err());
+}
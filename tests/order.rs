@@ -0,0 +1,133 @@
+//! Tests of byte orders including mixed-endian word swaps.
+
+use in_situ::{Dyn, Error, InSitu, InSituMut, Order};
+
+/// Byte orders to test.
+const ORDERS: [Order; 4] = [Order::BE, Order::LE, Order::CDAB, Order::BADC];
+
+#[test]
+fn layout() {
+    for (order, expected) in [
+        (Order::BE, [0x0a, 0x0b, 0x0c, 0x0d]),
+        (Order::LE, [0x0d, 0x0c, 0x0b, 0x0a]),
+        (Order::CDAB, [0x0c, 0x0d, 0x0a, 0x0b]),
+        (Order::BADC, [0x0b, 0x0a, 0x0d, 0x0c]),
+    ] {
+        let mut bytes = [0; 4];
+        let mut view = Dyn::<_>::new(&mut bytes[..], order);
+        view.set_u32(0, 0x0a0b_0c0d);
+        assert_eq!(view.as_ref(), expected, "{order:?}");
+        view.as_mut().fill(0);
+        view.set_uint(0, 0x0a0b_0c0d, 4);
+        assert_eq!(view.as_ref(), expected, "{order:?}");
+    }
+}
+
+#[test]
+fn round_trip() {
+    for order in ORDERS {
+        let mut bytes = [0; 16];
+        let mut view = Dyn::<_>::new(&mut bytes[..], order);
+        view.set_u16(0, 0x0102);
+        assert_eq!(view.u16(0), 0x0102, "{order:?}");
+        view.set_u32(0, 0x0102_0304);
+        assert_eq!(view.u32(0), 0x0102_0304, "{order:?}");
+        view.set_u64(0, 0x0102_0304_0506_0708);
+        assert_eq!(view.u64(0), 0x0102_0304_0506_0708, "{order:?}");
+        view.set_u128(0, 0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10);
+        assert_eq!(
+            view.u128(0),
+            0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10,
+            "{order:?}"
+        );
+        view.set_i16(0, -0x0102);
+        assert_eq!(view.i16(0), -0x0102, "{order:?}");
+        view.set_i32(0, -0x0102_0304);
+        assert_eq!(view.i32(0), -0x0102_0304, "{order:?}");
+        view.set_i64(0, -0x0102_0304_0506_0708);
+        assert_eq!(view.i64(0), -0x0102_0304_0506_0708, "{order:?}");
+        view.set_i128(0, -0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10);
+        assert_eq!(
+            view.i128(0),
+            -0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10,
+            "{order:?}"
+        );
+        view.set_f32(0, -1.5);
+        assert_eq!(view.f32(0).to_bits(), (-1.5f32).to_bits(), "{order:?}");
+        view.set_f64(0, -1.5);
+        assert_eq!(view.f64(0).to_bits(), (-1.5f64).to_bits(), "{order:?}");
+        for word_size in (2..=8).step_by(2) {
+            let value = 0x0102_0304_0506_0708 >> ((8 - word_size) * 8);
+            view.set_uint(0, value, word_size);
+            assert_eq!(view.uint(0, word_size), value, "{order:?} {word_size}");
+            view.set_int(0, -1, word_size);
+            assert_eq!(view.int(0, word_size), -1, "{order:?} {word_size}");
+            view.set_int(0, -0x0102, word_size);
+            assert_eq!(view.int(0, word_size), -0x0102, "{order:?} {word_size}");
+        }
+        for word_size in (2..=16).step_by(2) {
+            let value = 0x0102_0304_0506_0708_090a_0b0c_0d0e_0f10 >> ((16 - word_size) * 8);
+            view.set_uint128(0, value, word_size);
+            assert_eq!(view.uint128(0, word_size), value, "{order:?} {word_size}");
+            view.set_int128(0, -0x0102, word_size);
+            assert_eq!(view.int128(0, word_size), -0x0102, "{order:?} {word_size}");
+        }
+    }
+}
+
+#[test]
+fn round_trip_odd() {
+    for order in [Order::BE, Order::LE] {
+        let mut bytes = [0; 16];
+        let mut view = Dyn::<_>::new(&mut bytes[..], order);
+        view.set_u24(0, 0x01_0203);
+        assert_eq!(view.u24(0), 0x01_0203, "{order:?}");
+        view.set_i24(0, -0x01_0203);
+        assert_eq!(view.i24(0), -0x01_0203, "{order:?}");
+        view.set_uint(0, 0x01_0203_0405, 5);
+        assert_eq!(view.uint(0, 5), 0x01_0203_0405, "{order:?}");
+        view.set_int128(0, -0x01_0203_0405, 7);
+        assert_eq!(view.int128(0, 7), -0x01_0203_0405, "{order:?}");
+    }
+}
+
+#[test]
+fn variable_width() {
+    let bytes: [u8; 16] = core::array::from_fn(|index| u8::try_from(index * 15 + 1).unwrap());
+    for order in ORDERS {
+        let view = Dyn::<_>::new(&bytes[..], order);
+        assert_eq!(view.uint(0, 2), u64::from(view.u16(0)), "{order:?}");
+        assert_eq!(view.uint(0, 4), u64::from(view.u32(0)), "{order:?}");
+        assert_eq!(view.uint(0, 8), view.u64(0), "{order:?}");
+        assert_eq!(view.uint128(0, 16), view.u128(0), "{order:?}");
+        assert_eq!(view.int(0, 4), i64::from(view.i32(0)), "{order:?}");
+        assert_eq!(view.int(0, 8), view.i64(0), "{order:?}");
+        assert_eq!(view.int128(0, 16), view.i128(0), "{order:?}");
+    }
+}
+
+#[test]
+fn word_swap() {
+    for order in [Order::CDAB, Order::BADC] {
+        let mut bytes = [0; 16];
+        let mut view = Dyn::<_>::new(&mut bytes[..], order);
+        assert_eq!(view.try_u24(0), Err(Error::WordSwap { word_size: 3 }));
+        assert_eq!(view.try_i24(0), Err(Error::WordSwap { word_size: 3 }));
+        assert_eq!(view.try_uint(0, 5), Err(Error::WordSwap { word_size: 5 }));
+        assert_eq!(view.try_int128(0, 9), Err(Error::WordSwap { word_size: 9 }));
+        assert_eq!(
+            view.try_set_u24(0, 1),
+            Err(Error::WordSwap { word_size: 3 })
+        );
+        assert_eq!(
+            view.try_set_uint128(0, 1, 15),
+            Err(Error::WordSwap { word_size: 15 })
+        );
+        assert_eq!(
+            view.set_int_checked(0, 1, 7),
+            Err(Error::WordSwap { word_size: 7 })
+        );
+        assert_eq!(view.try_uint(0, 1), Ok(0));
+        assert_eq!(view.as_ref(), [0; 16]);
+    }
+}
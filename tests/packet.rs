@@ -0,0 +1,54 @@
+//! Tests of [`Packet`].
+
+use in_situ::{Error, InSitu, InSituMut, Order, Packet, Raw};
+
+/// Bytes `0..16`.
+const BYTES: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+#[test]
+fn new() {
+    for swap_size in [0, 1, 2, 4, 8, 16, 1 << 20] {
+        let packet = Packet::new(&BYTES[..], Order::LE, swap_size).unwrap();
+        assert_eq!(packet.swap_size(), swap_size);
+        assert_eq!(packet.order(), Order::LE);
+        assert_eq!(packet.into_raw(), BYTES);
+    }
+    for swap_size in [3, 6, 12, usize::MAX] {
+        assert_eq!(
+            Packet::new(&BYTES[..], Order::BE, swap_size),
+            Err(Error::SwapSize { swap_size })
+        );
+    }
+}
+
+#[test]
+fn split() {
+    for order in [Order::BE, Order::LE, Order::CDAB, Order::BADC] {
+        let mut head = Packet::new(&BYTES[..], order, 4).unwrap();
+        let mut tail = head.split_off(8);
+        let body = tail.split_to(4);
+        for packet in [head, body, tail] {
+            assert_eq!(packet.order(), order);
+            assert_eq!(packet.swap_size(), 4);
+            assert_eq!(packet.is_be(), order.is_be());
+            assert_eq!(packet.is_word_swapped(), order.is_word_swapped());
+        }
+        assert_eq!(head.as_ref(), &BYTES[..8]);
+        assert_eq!(body.as_ref(), &BYTES[8..12]);
+        assert_eq!(tail.as_ref(), &BYTES[12..]);
+        let whole = Packet::new(&BYTES[..], order, 4).unwrap();
+        assert_eq!(body.u16(2), whole.u16(10), "{order:?}");
+        assert_eq!(tail.u32(0), whole.u32(12), "{order:?}");
+    }
+}
+
+#[test]
+fn split_mut() {
+    let mut bytes = BYTES;
+    let mut head = Packet::new(&mut bytes[..], Order::LE, 2).unwrap();
+    let mut tail = head.split_off(4);
+    assert_eq!((tail.order(), tail.swap_size()), (Order::LE, 2));
+    tail.set_u8(0, 0xaa);
+    head.set_u8(3, 0xbb);
+    assert_eq!(bytes[..6], [0, 1, 0xbb, 3, 4, 0xaa]);
+}
@@ -0,0 +1,126 @@
+//! Tests of checked, saturating, and wrapping setters.
+
+use in_situ::{Dyn, Error, InSitu, InSituMut, Order};
+
+/// Byte orders to test.
+const ORDERS: [Order; 2] = [Order::BE, Order::LE];
+
+#[test]
+fn checked() {
+    for order in ORDERS {
+        let mut bytes = [0; 16];
+        let mut view = Dyn::<_>::new(&mut bytes[..], order);
+        assert_eq!(view.set_uint_checked(0, 0xff_ffff, 3), Ok(()));
+        assert_eq!(view.uint(0, 3), 0xff_ffff, "{order:?}");
+        assert_eq!(
+            view.set_uint_checked(0, 0x100_0000, 3),
+            Err(Error::Overflow { word_size: 3 })
+        );
+        assert_eq!(view.uint(0, 3), 0xff_ffff, "{order:?}");
+        assert_eq!(view.set_int_checked(0, -0x80_0000, 3), Ok(()));
+        assert_eq!(view.int(0, 3), -0x80_0000, "{order:?}");
+        assert_eq!(
+            view.set_int_checked(0, -0x80_0001, 3),
+            Err(Error::Overflow { word_size: 3 })
+        );
+        assert_eq!(
+            view.set_int_checked(0, 0x80, 1),
+            Err(Error::Overflow { word_size: 1 })
+        );
+        assert_eq!(
+            view.set_uint128_checked(0, 1 << 72, 9),
+            Err(Error::Overflow { word_size: 9 })
+        );
+        assert_eq!(
+            view.set_int128_checked(0, -(1 << 71) - 1, 9),
+            Err(Error::Overflow { word_size: 9 })
+        );
+        assert_eq!(
+            view.set_i24_checked(0, 0x80_0000),
+            Err(Error::Overflow { word_size: 3 })
+        );
+        assert_eq!(view.int(0, 3), -0x80_0000, "{order:?}");
+        assert_eq!(
+            view.set_uint_checked(0, 0, 0),
+            Err(Error::WordSize {
+                word_size: 0,
+                max: 8
+            })
+        );
+    }
+}
+
+#[test]
+fn saturating() {
+    for order in ORDERS {
+        let mut bytes = [0; 16];
+        let mut view = Dyn::<_>::new(&mut bytes[..], order);
+        for (word_size, min, max) in [
+            (1, -0x80, 0x7f),
+            (3, -0x80_0000, 0x7f_ffff),
+            (8, i64::MIN, i64::MAX),
+        ] {
+            view.set_int_saturating(0, i64::MAX, word_size);
+            assert_eq!(view.int(0, word_size), max, "{order:?}");
+            view.set_int_saturating(0, i64::MIN, word_size);
+            assert_eq!(view.int(0, word_size), min, "{order:?}");
+            view.set_int_saturating(0, -1, word_size);
+            assert_eq!(view.int(0, word_size), -1, "{order:?}");
+            view.set_int128_saturating(0, i128::MAX, word_size);
+            assert_eq!(view.int(0, word_size), max, "{order:?}");
+            view.set_int128_saturating(0, i128::MIN, word_size);
+            assert_eq!(view.int(0, word_size), min, "{order:?}");
+        }
+        for (word_size, max) in [(1, 0xff), (3, 0xff_ffff), (8, u64::MAX)] {
+            view.set_uint_saturating(0, u64::MAX, word_size);
+            assert_eq!(view.uint(0, word_size), max, "{order:?}");
+            view.set_uint_saturating(0, 1, word_size);
+            assert_eq!(view.uint(0, word_size), 1, "{order:?}");
+            view.set_uint128_saturating(0, u128::MAX, word_size);
+            assert_eq!(view.uint(0, word_size), max, "{order:?}");
+        }
+        view.set_i24_saturating(0, i32::MIN);
+        assert_eq!(view.i24(0), -0x80_0000, "{order:?}");
+        view.set_i24_saturating(0, i32::MAX);
+        assert_eq!(view.i24(0), 0x7f_ffff, "{order:?}");
+    }
+}
+
+#[test]
+fn wrapping() {
+    for order in ORDERS {
+        let mut bytes = [0; 16];
+        let mut view = Dyn::<_>::new(&mut bytes[..], order);
+        for (word_size, value, unsigned, signed) in [
+            (1, 0x0102_0304_0506_0780_i64, 0x80, -0x80),
+            (3, 0x0102_0304_0580_0001, 0x80_0001, -0x7f_ffff),
+            (8, -1, u64::MAX, -1),
+        ] {
+            view.set_uint_wrapping(0, u64::from_ne_bytes(value.to_ne_bytes()), word_size);
+            assert_eq!(view.uint(0, word_size), unsigned, "{order:?}");
+            view.set_int_wrapping(0, value, word_size);
+            assert_eq!(view.int(0, word_size), signed, "{order:?}");
+            let wide = u128::from_ne_bytes(i128::from(value).to_ne_bytes());
+            view.set_uint128_wrapping(0, wide, word_size);
+            assert_eq!(view.uint(0, word_size), unsigned, "{order:?}");
+            view.set_int128_wrapping(0, value.into(), word_size);
+            assert_eq!(view.int(0, word_size), signed, "{order:?}");
+        }
+        view.set_i24_wrapping(0, 0x80_0000);
+        assert_eq!(view.i24(0), -0x80_0000, "{order:?}");
+    }
+}
+
+#[test]
+#[should_panic = "word size is zero or exceeds maximum"]
+fn saturating_word_size() {
+    let mut bytes = [0; 8];
+    Dyn::<_>::new(&mut bytes[..], Order::BE).set_uint_saturating(0, 1, 0);
+}
+
+#[test]
+#[should_panic = "word size is zero or exceeds maximum"]
+fn wrapping_word_size() {
+    let mut bytes = [0; 16];
+    Dyn::<_>::new(&mut bytes[..], Order::LE).set_int_wrapping(0, 1, 9);
+}
@@ -0,0 +1,111 @@
+//! Tests of offset translation in slices of swap size.
+
+#[cfg(feature = "alloc")]
+use std::borrow::Cow;
+
+use in_situ::{Dyn, Error, InSitu, InSituMut, Le, Order};
+
+/// Bytes `0..16`.
+const BYTES: [u8; 16] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15];
+
+#[test]
+fn be() {
+    let view = Dyn::<_, 8>::new(&BYTES[..], Order::BE);
+    assert_eq!(view.try_u32(6), Ok(0x0607_0809));
+    assert_eq!(view.try_u24(1), Ok(0x01_0203));
+    assert_eq!(view.try_u64(4), Ok(0x0405_0607_0809_0a0b));
+    assert_eq!(view.u32(6), 0x0607_0809);
+}
+
+#[test]
+fn le() {
+    let view = Le::<_, 8>(&BYTES[..]);
+    assert_eq!(view.try_u32(0), Ok(0x0706_0504));
+    assert_eq!(view.try_u32(4), Ok(0x0302_0100));
+    assert_eq!(view.try_u24(9), Ok(0x0e_0d0c));
+    assert_eq!(view.try_u8(15), Ok(8));
+    assert_eq!(
+        view.try_u32(6),
+        Err(Error::Crossing {
+            offset: 6,
+            word_size: 4,
+            swap_size: 8
+        })
+    );
+    let mut bytes = [0; 16];
+    let mut view = Le::<_, 8>(&mut bytes[..]);
+    view.set_u32(4, 0x0302_0100);
+    assert_eq!(view.0[..4], [0, 1, 2, 3]);
+}
+
+#[test]
+fn spanning() {
+    let view = Le::<_, 2>(&BYTES[..]);
+    assert_eq!(view.try_u24(0), Ok(0x02_0100));
+    assert_eq!(view.try_u24(1), Ok(0x03_0201));
+    assert_eq!(view.try_uint(3, 5), Ok(0x07_0605_0403));
+    assert_eq!(view.u24(1), 0x03_0201);
+    let view = Le::<_, 4>(&BYTES[..]);
+    assert_eq!(view.try_u64(2), Ok(0x0908_0706_0504_0302));
+    assert_eq!(view.try_u32(1), Ok(0x0403_0201));
+    let view = Dyn::<_, 2>::new(&BYTES[..], Order::BE);
+    assert_eq!(view.try_u24(1), Ok(0x01_0203));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn utf8_unswapped() {
+    // Spans several slices and starts and ends partway through a slice.
+    let (offset, string) = (1, "ATA model #");
+    let end = offset + string.len();
+    let bytes = swapped::<2>(offset, string);
+    assert_eq!(&bytes[..=end], b"A ATm dole#  ");
+    let bytes = swapped::<4>(offset, string);
+    assert_eq!(&bytes[..=end], b"ATA dom # le ");
+    for bytes in [
+        swapped::<2>(offset, string),
+        swapped::<4>(offset, string),
+        swapped::<8>(offset, string),
+    ] {
+        assert!(bytes[..end] != *string.as_bytes());
+    }
+    let view = Le::<_, 2>(swapped::<2>(offset, string));
+    let value = view.utf8_unswapped(offset, string.len()).unwrap();
+    assert!(matches!(value, Cow::Owned(_)));
+    assert_eq!(value, string);
+    let view = Le::<_, 4>(swapped::<4>(offset, string));
+    assert_eq!(view.utf8_unswapped(offset, string.len()).unwrap(), string);
+    let view = Le::<_, 8>(swapped::<8>(offset, string));
+    assert_eq!(view.utf8_unswapped(offset, string.len()).unwrap(), string);
+    assert_eq!(view.utf8_unswapped(offset + 4, 5).unwrap(), "model");
+    assert_eq!(view.utf8_unswapped(offset, 0).unwrap(), "");
+    assert_eq!(
+        view.utf8_unswapped(12, 5),
+        Err(Error::OutOfBounds {
+            offset: 12,
+            word_size: 5,
+            length: 16
+        })
+    );
+    let mut view = view;
+    view.set_u8(offset, 0xff);
+    assert!(matches!(
+        view.utf8_unswapped(offset, string.len()),
+        Err(Error::Utf8(_))
+    ));
+    let view = Dyn::<_, 4>::new(string.as_bytes(), Order::BE);
+    let value = view.utf8_unswapped(0, string.len()).unwrap();
+    assert!(matches!(value, Cow::Borrowed(_)));
+    assert_eq!(value, string);
+}
+
+/// Writes `string` at `offset` into 16 bytes swapped in little-endian slices of `SWAP` bytes.
+#[cfg(feature = "alloc")]
+fn swapped<const SWAP: usize>(offset: usize, string: &str) -> Vec<u8> {
+    let mut bytes = vec![b' '; 16];
+    let mut view = Le::<_, SWAP>(&mut bytes[..]);
+    for (index, &byte) in string.as_bytes().iter().enumerate() {
+        view.set_u8(offset + index, byte);
+    }
+    bytes
+}
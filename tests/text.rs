@@ -0,0 +1,167 @@
+//! Tests of strings terminated, prefixed, and padded.
+
+#[cfg(feature = "bstr")]
+use in_situ::Le;
+use in_situ::{Be, Dyn, Error, InSitu, InSituMut, Order};
+
+#[test]
+fn terminated_bounds() {
+    let bytes = [0; 4];
+    let view = Be(&bytes[..]);
+    assert_eq!(view.cstr(4, 0), Ok(""));
+    assert_eq!(
+        view.cstr(8, 0),
+        Err(Error::OutOfBounds {
+            offset: 8,
+            word_size: 0,
+            length: 4
+        })
+    );
+    assert_eq!(
+        view.cstr(usize::MAX, 2),
+        Err(Error::OutOfBounds {
+            offset: usize::MAX,
+            word_size: 0,
+            length: 4
+        })
+    );
+    #[cfg(feature = "bstr")]
+    assert_eq!(
+        view.cbstr(8, 0),
+        Err(Error::OutOfBounds {
+            offset: 8,
+            word_size: 0,
+            length: 4
+        })
+    );
+}
+
+#[test]
+fn cstr() {
+    let bytes = *b"abc\0defgh";
+    let view = Be(&bytes[..]);
+    assert_eq!(view.cstr(0, 9), Ok("abc"));
+    assert_eq!(view.cstr(0, 3), Ok("abc"));
+    assert_eq!(view.cstr(0, 2), Ok("ab"));
+    assert_eq!(view.cstr(4, 5), Ok("defgh"));
+    assert_eq!(view.cstr(3, 5), Ok(""));
+    assert_eq!(
+        view.cstr(4, 6),
+        Err(Error::OutOfBounds {
+            offset: 4,
+            word_size: 6,
+            length: 9
+        })
+    );
+    assert!(matches!(Be(&b"\xff\0"[..]).cstr(0, 2), Err(Error::Utf8(_))));
+}
+
+#[test]
+fn set_cstr() {
+    let mut bytes = [0xff; 8];
+    let mut view = Be(&mut bytes[..]);
+    assert_eq!(view.set_cstr(0, 8, "abc"), Ok(3));
+    assert_eq!(view.as_ref(), b"abc\0\0\0\0\0");
+    assert_eq!(view.set_cstr(0, 4, "abcdef"), Ok(3));
+    assert_eq!(view.cstr(0, 4), Ok("abc"));
+    assert_eq!(view.set_cstr(0, 4, "aäb"), Ok(3));
+    assert_eq!(view.set_cstr(0, 3, "aäb"), Ok(1));
+    assert_eq!(&view.as_ref()[..3], b"a\0\0");
+    assert_eq!(view.set_cstr(0, 0, "a"), Ok(0));
+    assert_eq!(
+        view.set_cstr(6, 3, "a"),
+        Err(Error::OutOfBounds {
+            offset: 6,
+            word_size: 3,
+            length: 8
+        })
+    );
+}
+
+#[test]
+fn pstr() {
+    for order in [Order::BE, Order::LE] {
+        for prefix_size in [1, 2, 4] {
+            let mut bytes = [0; 12];
+            let mut view = Dyn::<_>::new(&mut bytes[..], order);
+            assert_eq!(view.set_pstr(1, prefix_size, "abcde"), Ok(5));
+            assert_eq!(view.uint(1, prefix_size), 5, "{order:?}");
+            assert_eq!(view.pstr(1, prefix_size), Ok("abcde"), "{order:?}");
+            let prefix = &view.as_ref()[1..=prefix_size];
+            let expected = if order.is_be() { prefix_size } else { 1 };
+            assert_eq!(prefix[expected - 1], 5, "{order:?}");
+            assert_eq!(&view.as_ref()[1 + prefix_size..][..5], b"abcde");
+        }
+        let mut bytes = [0; 4];
+        let mut view = Dyn::<_>::new(&mut bytes[..], order);
+        view.set_u16(0, 3);
+        assert_eq!(
+            view.pstr(0, 2),
+            Err(Error::OutOfBounds {
+                offset: 2,
+                word_size: 3,
+                length: 4
+            })
+        );
+        assert_eq!(
+            view.set_pstr(0, 2, "abc"),
+            Err(Error::OutOfBounds {
+                offset: 0,
+                word_size: 5,
+                length: 4
+            })
+        );
+        assert_eq!(view.u16(0), 3, "{order:?}");
+        assert_eq!(
+            view.pstr(0, 0),
+            Err(Error::WordSize {
+                word_size: 0,
+                max: 8
+            })
+        );
+    }
+    let mut bytes = [0; 300];
+    let mut view = Be(&mut bytes[..]);
+    let long = "ä".repeat(200);
+    assert_eq!(view.set_pstr(0, 1, &long), Ok(254));
+    assert_eq!(view.pstr(0, 1), Ok(&long[..254]));
+}
+
+#[test]
+fn padded_utf8() {
+    let mut bytes = [0; 8];
+    let mut view = Be(&mut bytes[..]);
+    assert_eq!(view.set_padded_utf8(0, 8, "ab c", b' '), Ok(4));
+    assert_eq!(view.as_ref(), b"ab c    ");
+    assert_eq!(view.padded_utf8(0, 8, b' '), Ok("ab c"));
+    assert_eq!(view.padded_utf8(0, 8, 0), Ok("ab c    "));
+    assert_eq!(view.set_padded_utf8(0, 8, "ab\0", 0), Ok(3));
+    assert_eq!(view.padded_utf8(0, 8, 0), Ok("ab"));
+    assert_eq!(view.set_padded_utf8(0, 4, "abcäd", 0), Ok(3));
+    assert_eq!(view.padded_utf8(0, 4, 0), Ok("abc"));
+    assert_eq!(view.set_padded_utf8(0, 5, "abcäd", b' '), Ok(5));
+    assert_eq!(view.padded_utf8(0, 5, b' '), Ok("abcä"));
+    assert_eq!(view.set_padded_utf8(0, 8, "", b' '), Ok(0));
+    assert_eq!(view.padded_utf8(0, 8, b' '), Ok(""));
+    assert_eq!(
+        view.padded_utf8(4, 5, b' '),
+        Err(Error::OutOfBounds {
+            offset: 4,
+            word_size: 5,
+            length: 8
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "bstr")]
+fn bstr() {
+    let mut bytes = [0; 8];
+    let mut view = Le::<_>(&mut bytes[..]);
+    assert_eq!(view.set_cbstr(0, 4, b"\xffabc"), Ok(3));
+    assert_eq!(view.cbstr(0, 4), Ok(b"\xffab".into()));
+    assert_eq!(view.set_pbstr(0, 2, b"\xfe\xff"), Ok(2));
+    assert_eq!(view.pbstr(0, 2), Ok(b"\xfe\xff".into()));
+    assert_eq!(view.set_padded_bstr(0, 8, b"\xff ", b' '), Ok(2));
+    assert_eq!(view.padded_bstr(0, 8, b' '), Ok(b"\xff".into()));
+}
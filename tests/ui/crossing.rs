@@ -0,0 +1,10 @@
+use in_situ::InSitu;
+
+#[derive(InSitu)]
+#[in_situ(wrapper = HeaderView, swap_size = 8)]
+struct Header {
+    #[in_situ(offset = 6)]
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use in_situ::InSitu;
+
+#[derive(InSitu)]
+#[in_situ(wrapper = HeaderView)]
+struct Header {
+    #[in_situ(offset = 18446744073709551615)]
+    id: u32,
+}
+
+fn main() {}
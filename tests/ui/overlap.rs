@@ -0,0 +1,12 @@
+use in_situ::InSitu;
+
+#[derive(InSitu)]
+#[in_situ(wrapper = HeaderView)]
+struct Header {
+    #[in_situ(offset = 0)]
+    length: u32,
+    #[in_situ(offset = 2)]
+    flags: u16,
+}
+
+fn main() {}
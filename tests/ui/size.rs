@@ -0,0 +1,12 @@
+use in_situ::InSitu;
+
+#[derive(InSitu)]
+#[in_situ(wrapper = HeaderView, size = 4)]
+struct Header {
+    #[in_situ(offset = 0)]
+    length: u16,
+    #[in_situ(offset = 2)]
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use in_situ::InSitu;
+
+#[derive(InSitu)]
+#[in_situ(wrapper = HeaderView, volatile = HeaderRegs, swap_size = 2)]
+struct Header {
+    #[in_situ(offset = 0)]
+    length: u16,
+}
+
+fn main() {}
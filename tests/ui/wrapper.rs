@@ -0,0 +1,9 @@
+use in_situ::InSitu;
+
+#[derive(InSitu)]
+struct Header {
+    #[in_situ(offset = 0)]
+    id: u32,
+}
+
+fn main() {}
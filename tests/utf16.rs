@@ -0,0 +1,31 @@
+//! Tests of UTF-16 strings.
+
+#[cfg(feature = "alloc")]
+use in_situ::InSitu;
+use in_situ::{Error, InSituMut, Le};
+
+#[test]
+fn set_utf16() {
+    let mut bytes = [0xff; 12];
+    let mut view = Le::<_>(&mut bytes[..]);
+    assert_eq!(view.set_utf16(2, 4, "a\u{1f600}"), Ok(()));
+    assert_eq!(
+        view.0,
+        [0xff, 0xff, b'a', 0, 0x3d, 0xd8, 0, 0xde, 0, 0, 0xff, 0xff]
+    );
+    assert_eq!(
+        view.set_utf16(2, 2, "a\u{1f600}"),
+        Err(Error::OutOfBounds {
+            offset: 2,
+            word_size: 6,
+            length: 6
+        })
+    );
+    assert!(matches!(
+        view.set_utf16(8, 4, "a"),
+        Err(Error::OutOfBounds { .. })
+    ));
+    assert_eq!(view.0[2..4], [b'a', 0]);
+    #[cfg(feature = "alloc")]
+    assert_eq!(view.utf16(2, 3).as_deref(), Ok("a\u{1f600}"));
+}
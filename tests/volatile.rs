@@ -0,0 +1,230 @@
+//! Tests of [`Volatile`] against heap allocations.
+
+use in_situ::{Dyn, Error, InSitu, Order, Volatile};
+#[cfg(feature = "alloc")]
+use in_situ::{Field, Kind, Layout, Value};
+
+/// Byte orders to test.
+const ORDERS: [Order; 4] = [Order::BE, Order::LE, Order::CDAB, Order::BADC];
+
+/// Allocates 64 zero bytes aligned to 16 bytes on the heap.
+fn heap() -> Vec<u128> {
+    vec![0; 4]
+}
+
+/// Borrows `words` as bytes.
+const fn bytes(words: &mut [u128]) -> &mut [u8] {
+    // SAFETY: The words are valid for reads and writes of their size and exclusively borrowed.
+    unsafe { core::slice::from_raw_parts_mut(words.as_mut_ptr().cast(), size_of_val(words)) }
+}
+
+#[test]
+fn round_trip() {
+    for order in ORDERS {
+        let mut words = heap();
+        let mut view = Volatile::from_slice(bytes(&mut words), order);
+        view.try_set_u8(0, 0x01).unwrap();
+        view.try_set_i8(1, -0x02).unwrap();
+        view.try_set_u16(2, 0x0304).unwrap();
+        view.try_set_u32(4, 0x0506_0708).unwrap();
+        view.try_set_u64(8, 0x090a_0b0c_0d0e_0f10).unwrap();
+        view.try_set_u128(16, 0x1112_1314_1516_1718_191a_1b1c_1d1e_1f20)
+            .unwrap();
+        view.try_set_f64(32, -1.5).unwrap();
+        view.try_set_f32(40, 0.25).unwrap();
+        view.try_set_i32(44, -0x2122_2324).unwrap();
+        view.try_set_i128(48, -0x2526_2728_292a_2b2c_2d2e_2f30_3132_3334)
+            .unwrap();
+        assert_eq!(view.try_u8(0), Ok(0x01));
+        assert_eq!(view.try_i8(1), Ok(-0x02));
+        assert_eq!(view.try_u16(2), Ok(0x0304));
+        assert_eq!(view.try_u32(4), Ok(0x0506_0708));
+        assert_eq!(view.try_u64(8), Ok(0x090a_0b0c_0d0e_0f10));
+        assert_eq!(
+            view.try_u128(16),
+            Ok(0x1112_1314_1516_1718_191a_1b1c_1d1e_1f20)
+        );
+        assert_eq!(view.try_f64(32), Ok(-1.5));
+        assert_eq!(view.try_f32(40), Ok(0.25));
+        assert_eq!(view.try_i32(44), Ok(-0x2122_2324));
+        assert_eq!(
+            view.try_i128(48),
+            Ok(-0x2526_2728_292a_2b2c_2d2e_2f30_3132_3334)
+        );
+        let bytes = bytes(&mut words);
+        let expected = Dyn::<_>::new(&bytes[..], order);
+        assert_eq!(expected.u16(2), 0x0304, "{order:?}");
+        assert_eq!(expected.u32(4), 0x0506_0708, "{order:?}");
+        assert_eq!(expected.u64(8), 0x090a_0b0c_0d0e_0f10, "{order:?}");
+        assert_eq!(expected.f64(32).to_bits(), (-1.5f64).to_bits(), "{order:?}");
+        assert_eq!(expected.i32(44), -0x2122_2324, "{order:?}");
+        assert_eq!(
+            expected.i128(48),
+            -0x2526_2728_292a_2b2c_2d2e_2f30_3132_3334,
+            "{order:?}"
+        );
+    }
+}
+
+#[test]
+fn errors() {
+    let mut words = heap();
+    let mut view = Volatile::from_slice(&mut bytes(&mut words)[..20], Order::LE);
+    assert_eq!(view.len(), 20);
+    assert_eq!(
+        view.try_u32(2),
+        Err(Error::Misaligned {
+            offset: 2,
+            align: 4
+        })
+    );
+    assert_eq!(
+        view.try_set_u16(1, 0xffff),
+        Err(Error::Misaligned {
+            offset: 1,
+            align: 2
+        })
+    );
+    assert_eq!(
+        view.try_u64(16),
+        Err(Error::OutOfBounds {
+            offset: 16,
+            word_size: 8,
+            length: 20
+        })
+    );
+    assert_eq!(
+        view.try_set_u8(20, 0xff),
+        Err(Error::OutOfBounds {
+            offset: 20,
+            word_size: 1,
+            length: 20
+        })
+    );
+    assert_eq!(
+        view.try_u128(usize::MAX),
+        Err(Error::OutOfBounds {
+            offset: usize::MAX,
+            word_size: 16,
+            length: 20
+        })
+    );
+    assert_eq!(view.try_u32(16), Ok(0));
+    assert!(bytes(&mut words).iter().all(|&byte| byte == 0));
+}
+
+#[test]
+fn variable_width() {
+    for order in ORDERS {
+        let mut words = heap();
+        let mut view = Volatile::from_slice(bytes(&mut words), order);
+        view.try_set_bool(0, true).unwrap();
+        view.try_set_uint(4, 0x0102_0304, 4).unwrap();
+        view.try_set_int(8, -0x0506, 2).unwrap();
+        view.try_set_uint128(16, 0x0708_090a_0b0c_0d0e, 8).unwrap();
+        view.try_set_int128(32, -0x0f10_1112_1314_1516_1718_191a_1b1c_1d1e, 16)
+            .unwrap();
+        assert_eq!(view.try_bool(0), Ok(true));
+        assert_eq!(view.try_uint(4, 4), Ok(0x0102_0304));
+        assert_eq!(view.try_int(8, 2), Ok(-0x0506));
+        assert_eq!(view.try_uint128(16, 8), Ok(0x0708_090a_0b0c_0d0e));
+        assert_eq!(
+            view.try_int128(32, 16),
+            Ok(-0x0f10_1112_1314_1516_1718_191a_1b1c_1d1e)
+        );
+        assert_eq!(view.try_u32(4), Ok(0x0102_0304), "{order:?}");
+        assert_eq!(view.try_i16(8), Ok(-0x0506), "{order:?}");
+        assert_eq!(view.try_u64(16), Ok(0x0708_090a_0b0c_0d0e), "{order:?}");
+        assert_eq!(
+            view.try_uint(5, 2),
+            Err(Error::Misaligned {
+                offset: 5,
+                align: 2
+            })
+        );
+        assert_eq!(
+            view.try_uint(0, 9),
+            Err(Error::WordSize {
+                word_size: 9,
+                max: 8
+            })
+        );
+        if order.is_word_swapped() {
+            assert_eq!(view.try_u24(1), Err(Error::WordSwap { word_size: 3 }));
+            assert_eq!(
+                view.try_set_i24(1, -1),
+                Err(Error::WordSwap { word_size: 3 })
+            );
+            assert_eq!(
+                view.try_set_uint(1, 1, 5),
+                Err(Error::WordSwap { word_size: 5 })
+            );
+            continue;
+        }
+        view.try_set_u24(49, 0x01_0203).unwrap();
+        view.try_set_i24(53, -0x04_0506).unwrap();
+        view.try_set_uint(57, 0x07_0809_0a0b, 5).unwrap();
+        assert_eq!(view.try_u24(49), Ok(0x01_0203));
+        assert_eq!(view.try_i24(53), Ok(-0x04_0506));
+        assert_eq!(view.try_uint(57, 5), Ok(0x07_0809_0a0b));
+        assert_eq!(view.try_int128(57, 5), Ok(0x07_0809_0a0b));
+        assert_eq!(
+            view.try_set_uint(61, 0, 5),
+            Err(Error::OutOfBounds {
+                offset: 61,
+                word_size: 5,
+                length: 64
+            })
+        );
+        let bytes = bytes(&mut words);
+        let expected = Dyn::<_>::new(&bytes[..], order);
+        assert_eq!(expected.u24(49), 0x01_0203, "{order:?}");
+        assert_eq!(expected.i24(53), -0x04_0506, "{order:?}");
+        assert_eq!(expected.uint(57, 5), 0x07_0809_0a0b, "{order:?}");
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn layout() {
+    const INNER: Layout<'static> = Layout::new(&[
+        Field::new("id", 0, Kind::U16),
+        Field::new("value", 5, Kind::I24),
+    ]);
+    const OUTER: Layout<'static> = Layout::new(&[
+        Field::new("flag", 0, Kind::Bool),
+        Field::new("count", 4, Kind::U32),
+        Field::new("inner", 8, Kind::Layout(&INNER)),
+        Field::new("ratio", 16, Kind::F64),
+    ]);
+    let mut words = heap();
+    let mut view = Volatile::from_slice(bytes(&mut words), Order::LE);
+    view.try_set_bool(0, true).unwrap();
+    view.try_set_u32(4, 7).unwrap();
+    view.try_set_u16(8, 0x0102).unwrap();
+    view.try_set_i24(13, -3).unwrap();
+    view.try_set_f64(16, 0.5).unwrap();
+    assert_eq!(
+        OUTER.evaluate_volatile(&view),
+        Ok(vec![
+            ("flag", Value::Bool(true)),
+            ("count", Value::U32(7)),
+            (
+                "inner",
+                Value::Layout(vec![("id", Value::U16(0x0102)), ("value", Value::I24(-3))])
+            ),
+            ("ratio", Value::F64(0.5)),
+        ])
+    );
+    assert_eq!(
+        Field::new("name", 24, Kind::Utf8(4)).evaluate_volatile(&view),
+        Err(Error::NotVolatile { offset: 24 })
+    );
+    assert_eq!(
+        Field::new("count", 2, Kind::U32).evaluate_volatile(&view),
+        Err(Error::Misaligned {
+            offset: 2,
+            align: 4
+        })
+    );
+}